@@ -0,0 +1,470 @@
+//! The command-line argument parser.
+
+// Uses
+use std::path::PathBuf;
+
+use breeze::presentation::ThemePreset;
+
+use crate::fullscreen::VideoModeRequest;
+
+// Constants
+const OPTION_PREFIX: &str = "--";
+/// Marks the end of the options, so that any remaining arguments are treated
+/// as file paths even if they start with the option prefix.
+const END_OF_OPTIONS_MARKER: &str = "--";
+
+const VALIDATE_OPTION_NAME: &str = "validate";
+const NO_ANIMATION_OPTION_NAME: &str = "no-animation";
+const LIST_FONTS_OPTION_NAME: &str = "list-fonts";
+const LIST_SLIDES_OPTION_NAME: &str = "list-slides";
+const THEME_OPTION_NAME: &str = "theme";
+const FORCE_THEME_OPTION_NAME: &str = "force-theme";
+const MIRROR_OPTION_NAME: &str = "mirror";
+const KIOSK_OPTION_NAME: &str = "kiosk";
+const GOTO_OPTION_NAME: &str = "goto";
+const QUIET_OPTION_NAME: &str = "quiet";
+const MANIFEST_OPTION_NAME: &str = "manifest";
+const DESIGN_OPTION_NAME: &str = "design";
+const EXCLUSIVE_FULLSCREEN_OPTION_NAME: &str = "exclusive-fullscreen";
+const VIDEO_MODE_OPTION_NAME: &str = "mode";
+const TIMINGS_OPTION_NAME: &str = "timings";
+const VSYNC_OPTION_NAME: &str = "vsync";
+const DEMO_OPTION_NAME: &str = "demo";
+const SHUFFLE_OPTION_NAME: &str = "shuffle";
+const SEED_OPTION_NAME: &str = "seed";
+const NO_INHIBIT_SLEEP_OPTION_NAME: &str = "no-inhibit-sleep";
+const NAVIGATION_SOUND_OPTION_NAME: &str = "nav-sound";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Arguments {
+	pub file_paths:           Vec<PathBuf>,
+	/// Only check that the presentation loads successfully, without displaying
+	/// it.
+	pub validate:             bool,
+	/// Disable all animations, regardless of the presentation's options.
+	pub no_animation:         bool,
+	/// Print the family names of the available fonts, instead of running a
+	/// presentation.
+	pub list_fonts:           bool,
+	/// Print a summary of each slide, instead of running the presentation.
+	pub list_slides:          bool,
+	/// A built-in colour scheme to use for any colours the presentation doesn't
+	/// set.
+	pub theme:                Option<ThemePreset>,
+	/// Use the theme preset even if the presentation sets its own colours.
+	pub force_theme:          bool,
+	/// Mirror the output horizontally, regardless of the presentation's
+	/// options.
+	pub mirror:               bool,
+	/// Prevent the presentation from being closed by accident, regardless of
+	/// the presentation's options.
+	pub kiosk:                bool,
+	/// The anchor of the slide to start on.
+	pub goto:                 Option<String>,
+	/// Don't print anything to the command line other than errors.
+	pub quiet:                bool,
+	/// A file listing the presentation files to load, in order, before any
+	/// others.
+	pub manifest:             Option<PathBuf>,
+	/// Enable tools for composing slides, such as the design guides.
+	pub design:               bool,
+	/// Use exclusive fullscreen instead of borderless fullscreen, for lower
+	/// latency.
+	pub exclusive_fullscreen: bool,
+	/// The video mode to use for exclusive fullscreen, instead of the best one
+	/// available.
+	pub video_mode:           Option<VideoModeRequest>,
+	/// A file listing the times to advance the slides at.
+	pub timings:              Option<PathBuf>,
+	/// Whether drawing is synchronised with the display's refresh rate.
+	pub vsync:                Option<bool>,
+	/// Run the built-in demo presentation instead of any files.
+	pub demo:                 bool,
+	/// Show the slides in a random order, regardless of the presentation's
+	/// options.
+	pub shuffle:              bool,
+	/// The seed for shuffling the slides, so that the order can be reproduced.
+	pub seed:                 Option<u64>,
+	/// Allow the system to sleep or start the screensaver while the
+	/// presentation is fullscreen.
+	pub no_inhibit_sleep:     bool,
+	/// Play a short sound whenever the slide changes, regardless of the
+	/// presentation's options.
+	pub navigation_sound:     bool,
+}
+
+impl Arguments {
+	/// Parses the arguments, excluding the program name.
+	pub fn parse<I>(arguments: I) -> Result<Self, String>
+	where
+		I: IntoIterator<Item = String>,
+	{
+		let mut result = Self::default();
+
+		let mut arguments = arguments.into_iter();
+		while let Some(argument) = arguments.next() {
+			if argument == END_OF_OPTIONS_MARKER {
+				result
+					.file_paths
+					.extend(arguments.by_ref().map(PathBuf::from));
+				break;
+			}
+
+			let Some(option_name) = argument.strip_prefix(OPTION_PREFIX) else {
+				result.file_paths.push(PathBuf::from(argument));
+				continue;
+			};
+
+			match option_name {
+				VALIDATE_OPTION_NAME => result.validate = true,
+				NO_ANIMATION_OPTION_NAME => result.no_animation = true,
+				LIST_FONTS_OPTION_NAME => result.list_fonts = true,
+				LIST_SLIDES_OPTION_NAME => result.list_slides = true,
+				THEME_OPTION_NAME => {
+					let theme_name = arguments
+						.next()
+						.ok_or_else(|| format!("the option \"{argument}\" requires a value!"))?;
+					result.theme = Some(
+						theme_name
+							.parse()
+							.map_err(|()| format!("unknown theme \"{theme_name}\"!"))?,
+					);
+				}
+				FORCE_THEME_OPTION_NAME => result.force_theme = true,
+				MIRROR_OPTION_NAME => result.mirror = true,
+				KIOSK_OPTION_NAME => result.kiosk = true,
+				QUIET_OPTION_NAME => result.quiet = true,
+				DESIGN_OPTION_NAME => result.design = true,
+				DEMO_OPTION_NAME => result.demo = true,
+				SHUFFLE_OPTION_NAME => result.shuffle = true,
+				NO_INHIBIT_SLEEP_OPTION_NAME => result.no_inhibit_sleep = true,
+				NAVIGATION_SOUND_OPTION_NAME => result.navigation_sound = true,
+				SEED_OPTION_NAME => {
+					let seed = arguments
+						.next()
+						.ok_or_else(|| format!("the option \"{argument}\" requires a value!"))?;
+					result.seed =
+						Some(seed.parse().map_err(|_| {
+							format!("invalid seed \"{seed}\" - expected a number!")
+						})?);
+				}
+				EXCLUSIVE_FULLSCREEN_OPTION_NAME => result.exclusive_fullscreen = true,
+				VIDEO_MODE_OPTION_NAME => {
+					let video_mode = arguments
+						.next()
+						.ok_or_else(|| format!("the option \"{argument}\" requires a value!"))?;
+					result.video_mode = Some(video_mode.parse().map_err(|()| {
+						format!("invalid video mode \"{video_mode}\" - expected WxH or WxH@Hz!")
+					})?);
+				}
+				MANIFEST_OPTION_NAME => {
+					result.manifest =
+						Some(PathBuf::from(arguments.next().ok_or_else(|| {
+							format!("the option \"{argument}\" requires a value!")
+						})?));
+				}
+				VSYNC_OPTION_NAME => {
+					let vsync_mode = arguments
+						.next()
+						.ok_or_else(|| format!("the option \"{argument}\" requires a value!"))?;
+					result.vsync = Some(match vsync_mode.as_str() {
+						"on" => true,
+						"off" => false,
+						// The OpenGL backend can only wait for a fixed number of frames or not at
+						// all, so there's no adaptive swap interval to use
+						"adaptive" => {
+							return Err("adaptive vsync isn't supported - use \"on\" or \"off\" \
+							            instead!"
+								.to_owned())
+						}
+						_ => {
+							return Err(format!(
+								"unknown vsync mode \"{vsync_mode}\" - expected \"on\" or \"off\"!"
+							))
+						}
+					});
+				}
+				TIMINGS_OPTION_NAME => {
+					result.timings =
+						Some(PathBuf::from(arguments.next().ok_or_else(|| {
+							format!("the option \"{argument}\" requires a value!")
+						})?));
+				}
+				GOTO_OPTION_NAME => {
+					result.goto =
+						Some(arguments.next().ok_or_else(|| {
+							format!("the option \"{argument}\" requires a value!")
+						})?);
+				}
+				_ => return Err(format!("unknown option \"{argument}\"!")),
+			}
+		}
+
+		Ok(result)
+	}
+
+	/// Whether the requested mode doesn't need a window.
+	pub fn is_non_gui_mode(&self) -> bool {
+		self.validate || self.list_fonts || self.list_slides
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::path::PathBuf;
+
+	use breeze::presentation::ThemePreset;
+
+	use super::Arguments;
+	use crate::fullscreen::VideoModeRequest;
+
+	fn parse(arguments: &[&str]) -> Result<Arguments, String> {
+		Arguments::parse(arguments.iter().map(|&argument| argument.to_owned()))
+	}
+
+	#[test]
+	fn file_paths() {
+		let actual_result = parse(&["intro.sent", "--validate", "--quiet", "body.sent"]);
+
+		let expected_result = Ok(Arguments {
+			file_paths: vec![PathBuf::from("intro.sent"), PathBuf::from("body.sent")],
+			validate: true,
+			quiet: true,
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn end_of_options() {
+		let actual_result = parse(&["--", "--validate"]);
+
+		let expected_result = Ok(Arguments {
+			file_paths: vec![PathBuf::from("--validate")],
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn list_fonts() {
+		let actual_result = parse(&["--list-fonts"]);
+
+		let expected_result = Ok(Arguments {
+			list_fonts: true,
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn list_slides() {
+		let actual_result = parse(&["--list-slides", "slides.sent"]);
+
+		let expected_result = Ok(Arguments {
+			file_paths: vec![PathBuf::from("slides.sent")],
+			list_slides: true,
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn theme() {
+		let actual_result = parse(&["--theme", "dracula", "--force-theme", "slides.sent"]);
+
+		let expected_result = Ok(Arguments {
+			file_paths: vec![PathBuf::from("slides.sent")],
+			theme: Some(ThemePreset::Dracula),
+			force_theme: true,
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn theme_errors() {
+		let actual_result = [parse(&["--theme", "unknown"]), parse(&["--theme"])];
+
+		let expected_result = [
+			Err("unknown theme \"unknown\"!".to_owned()),
+			Err("the option \"--theme\" requires a value!".to_owned()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn goto() {
+		let actual_result = [
+			parse(&["--goto", "intro", "slides.sent"]),
+			parse(&["--goto"]),
+		];
+
+		let expected_result = [
+			Ok(Arguments {
+				file_paths: vec![PathBuf::from("slides.sent")],
+				goto: Some("intro".to_owned()),
+				..Default::default()
+			}),
+			Err("the option \"--goto\" requires a value!".to_owned()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn manifest() {
+		let actual_result = parse(&["--manifest", "order.txt", "extra.sent"]);
+
+		let expected_result = Ok(Arguments {
+			file_paths: vec![PathBuf::from("extra.sent")],
+			manifest: Some(PathBuf::from("order.txt")),
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn demo() {
+		let actual_result = parse(&["--demo"]);
+
+		let expected_result = Ok(Arguments {
+			demo: true,
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn shuffle() {
+		let actual_result = [
+			parse(&["--shuffle", "--seed", "42"]),
+			parse(&["--seed", "random"]),
+		];
+
+		let expected_result = [
+			Ok(Arguments {
+				shuffle: true,
+				seed: Some(42),
+				..Default::default()
+			}),
+			Err("invalid seed \"random\" - expected a number!".to_owned()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn no_inhibit_sleep() {
+		let actual_result = parse(&["--no-inhibit-sleep", "slides.sent"]);
+
+		let expected_result = Ok(Arguments {
+			file_paths: vec![PathBuf::from("slides.sent")],
+			no_inhibit_sleep: true,
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn navigation_sound() {
+		let actual_result = parse(&["--nav-sound", "slides.sent"]);
+
+		let expected_result = Ok(Arguments {
+			file_paths: vec![PathBuf::from("slides.sent")],
+			navigation_sound: true,
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn design() {
+		let actual_result = parse(&["--design", "slides.sent"]);
+
+		let expected_result = Ok(Arguments {
+			file_paths: vec![PathBuf::from("slides.sent")],
+			design: true,
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn timings() {
+		let actual_result = parse(&["slides.sent", "--timings", "cues.txt"]);
+
+		let expected_result = Ok(Arguments {
+			file_paths: vec![PathBuf::from("slides.sent")],
+			timings: Some(PathBuf::from("cues.txt")),
+			..Default::default()
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn vsync() {
+		let actual_result = [
+			parse(&["--vsync", "off"]),
+			parse(&["--vsync", "adaptive"]),
+			parse(&["--vsync", "sometimes"]),
+		];
+
+		let expected_result = [
+			Ok(Arguments {
+				vsync: Some(false),
+				..Default::default()
+			}),
+			Err("adaptive vsync isn't supported - use \"on\" or \"off\" instead!".to_owned()),
+			Err("unknown vsync mode \"sometimes\" - expected \"on\" or \"off\"!".to_owned()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn exclusive_fullscreen() {
+		let actual_result = [
+			parse(&["--exclusive-fullscreen", "--mode", "1920x1080@144"]),
+			parse(&["--mode", "fast"]),
+		];
+
+		let expected_result = [
+			Ok(Arguments {
+				exclusive_fullscreen: true,
+				video_mode: Some(VideoModeRequest {
+					width:        1920,
+					height:       1080,
+					refresh_rate: Some(144),
+				}),
+				..Default::default()
+			}),
+			Err("invalid video mode \"fast\" - expected WxH or WxH@Hz!".to_owned()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn unknown_option() {
+		let actual_result = parse(&["--unknown"]);
+
+		let expected_result = Err("unknown option \"--unknown\"!".to_owned());
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
@@ -0,0 +1,290 @@
+//! Bundles, which are zip archives of a presentation file along with the
+//! images it uses, so that a presentation can be shared as a single file.
+//!
+//! The presentation file must be at the root of the archive, with the name
+//! `presentation.sent`. Image paths in it are relative to the root of the
+//! archive.
+
+// Uses
+use std::{
+	fs::File,
+	io::{self, Cursor, Read},
+	path::{Path, PathBuf},
+};
+
+use image::{io::Reader as ImageReader, DynamicImage};
+use zip::ZipArchive;
+
+use crate::presentation::{decode_text, ParseError, Presentation};
+
+// Constants
+/// The file extension of bundles.
+pub const BUNDLE_EXTENSION: &str = "sentzip";
+/// The name of the presentation file within a bundle.
+const PRESENTATION_ENTRY_NAME: &str = "presentation.sent";
+/// The prefix that paths relative to the root of the archive may start with.
+const CURRENT_DIRECTORY_PREFIX: &str = "./";
+
+/// An open bundle, which the presentation and its images are read from.
+pub struct Bundle {
+	path:    PathBuf,
+	archive: ZipArchive<File>,
+}
+
+impl Bundle {
+	/// Whether a path refers to a bundle, going by its extension.
+	#[must_use]
+	pub fn is_bundle_path(path: &Path) -> bool {
+		path.extension()
+			.is_some_and(|extension| extension.eq_ignore_ascii_case(BUNDLE_EXTENSION))
+	}
+
+	/// Opens a bundle.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the file can't be read, or isn't a zip archive.
+	pub fn open<P>(path: P) -> Result<Self, ParseError>
+	where
+		P: AsRef<Path>,
+	{
+		let path = path.as_ref();
+		let archive = File::open(path)
+			.and_then(|file| ZipArchive::new(file).map_err(io::Error::from))
+			.map_err(|error| ParseError::UnreadableBundle {
+				path: path.to_owned(),
+				error,
+			})?;
+
+		Ok(Self {
+			path: path.to_owned(),
+			archive,
+		})
+	}
+
+	/// Loads the presentation in the bundle.
+	///
+	/// Unlike presentations loaded from the filesystem, the image paths are
+	/// left as they are, since they refer to entries in the bundle.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the bundle has no presentation file, it can't be
+	/// read, or it tries to include other files.
+	pub fn load_presentation(&mut self) -> Result<Presentation, ParseError> {
+		let file_contents = self
+			.read_entry(PRESENTATION_ENTRY_NAME)
+			.and_then(decode_text)
+			.map_err(|error| ParseError::UnreadableFile {
+				path: self.path.join(PRESENTATION_ENTRY_NAME),
+				error,
+			})?;
+
+		let (presentation, includes) = Presentation::parse(file_contents.as_str());
+		if let Some((_, include_path)) = includes.first() {
+			return Err(ParseError::IncludeInBundle {
+				path: self.path.join(include_path),
+			});
+		}
+
+		Ok(presentation)
+	}
+
+	/// Loads a single image from the bundle into memory.
+	///
+	/// # Errors
+	///
+	/// Returns a user-facing error message if the image isn't in the bundle, or
+	/// can't be loaded.
+	pub fn load_image(&mut self, image_path: &str) -> Result<DynamicImage, String> {
+		let image_bytes = self.read_entry(image_path).map_err(|_| {
+			format!(
+				"unable to open the image\n\"{}\"!",
+				self.path.join(image_path).to_string_lossy()
+			)
+		})?;
+
+		ImageReader::new(Cursor::new(image_bytes))
+			.with_guessed_format()
+			.map_err(|_| {
+				format!(
+					"unable to guess the format of the image\n\"{}\"!",
+					self.path.join(image_path).to_string_lossy()
+				)
+			})?
+			.decode()
+			.map_err(|_| {
+				format!(
+					"unable to load the image\n\"{}\"!",
+					self.path.join(image_path).to_string_lossy()
+				)
+			})
+	}
+
+	/// Reads the contents of an entry in the archive.
+	///
+	/// Returns an error if there's no such entry, or it can't be read.
+	fn read_entry(&mut self, entry_path: &str) -> io::Result<Vec<u8>> {
+		let entry_name = to_entry_name(entry_path);
+		let mut entry = self.archive.by_name(entry_name.as_str())?;
+
+		let mut contents = Vec::new();
+		entry.read_to_end(&mut contents)?;
+
+		Ok(contents)
+	}
+}
+
+/// Converts a path within a bundle to the name of its entry, which always
+/// uses forward slashes and has no leading `./`.
+fn to_entry_name(entry_path: &str) -> String {
+	let entry_name = entry_path.replace('\\', "/");
+
+	entry_name
+		.strip_prefix(CURRENT_DIRECTORY_PREFIX)
+		.map_or(entry_name.clone(), str::to_owned)
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::{
+		env::temp_dir,
+		fs::{remove_file, File},
+		io::{self, Cursor, ErrorKind, Write},
+		path::Path,
+	};
+
+	use image::{DynamicImage, ImageOutputFormat};
+	use zip::{write::FileOptions, ZipWriter};
+
+	use super::{to_entry_name, Bundle};
+	use crate::presentation::{ParseError, SlideContent};
+
+	/// Writes a bundle with the given entries to a path.
+	fn write_bundle(path: &Path, entries: &[(&str, &[u8])]) {
+		let mut writer = ZipWriter::new(File::create(path).unwrap());
+		for (entry_name, contents) in entries {
+			writer
+				.start_file(*entry_name, FileOptions::default())
+				.unwrap();
+			writer.write_all(contents).unwrap();
+		}
+		writer.finish().unwrap();
+	}
+
+	#[test]
+	fn bundle_path() {
+		let actual_result = [
+			Bundle::is_bundle_path(Path::new("talk.sentzip")),
+			Bundle::is_bundle_path(Path::new("talk.SENTZIP")),
+			Bundle::is_bundle_path(Path::new("talk.sent")),
+			Bundle::is_bundle_path(Path::new("sentzip")),
+		];
+
+		let expected_result = [true, true, false, false];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn entry_names() {
+		let actual_result = [
+			to_entry_name("image.png"),
+			to_entry_name("./images/image.png"),
+			to_entry_name("images\\image.png"),
+		];
+
+		let expected_result = ["image.png", "images/image.png", "images/image.png"];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn loading() {
+		let bundle_path = temp_dir().join("breeze-test-loading.sentzip");
+		let mut image_bytes = Vec::new();
+		DynamicImage::new_rgba8(4, 2)
+			.write_to(&mut Cursor::new(&mut image_bytes), ImageOutputFormat::Png)
+			.unwrap();
+		write_bundle(
+			&bundle_path,
+			&[
+				("presentation.sent", b"Hello, world!\n\n@./images/image.png"),
+				("images/image.png", image_bytes.as_slice()),
+			],
+		);
+
+		let mut bundle = Bundle::open(&bundle_path).unwrap();
+		let presentation = bundle.load_presentation().unwrap();
+		let actual_result = (
+			presentation
+				.slides
+				.into_iter()
+				.map(|slide| slide.content)
+				.collect::<Vec<_>>(),
+			bundle
+				.load_image("./images/image.png")
+				.map(|image| (image.width(), image.height())),
+			bundle.load_image("missing.png").map(|_| ()),
+		);
+		remove_file(&bundle_path).unwrap();
+
+		let expected_result = (
+			vec![
+				SlideContent::Text("Hello, world!".to_owned()),
+				SlideContent::Image("./images/image.png".to_owned()),
+			],
+			Ok((4, 2)),
+			Err(format!(
+				"unable to open the image\n\"{}\"!",
+				bundle_path.join("missing.png").to_string_lossy()
+			)),
+		);
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn missing_presentation() {
+		let bundle_path = temp_dir().join("breeze-test-missing-presentation.sentzip");
+		write_bundle(&bundle_path, &[("slides.sent", b"Hello, world!")]);
+
+		let actual_result = Bundle::open(&bundle_path)
+			.and_then(|mut bundle| bundle.load_presentation().map(|_| ()))
+			.map_err(|error| error.to_string());
+		remove_file(&bundle_path).unwrap();
+
+		let expected_result = Err(ParseError::UnreadableFile {
+			path:  bundle_path.join("presentation.sent"),
+			error: io::Error::from(ErrorKind::NotFound),
+		}
+		.to_string());
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn include_in_bundle() {
+		let bundle_path = temp_dir().join("breeze-test-include-in-bundle.sentzip");
+		write_bundle(
+			&bundle_path,
+			&[(
+				"presentation.sent",
+				b"Hello, world!\n\n#.include:intro.sent",
+			)],
+		);
+
+		let actual_result = Bundle::open(&bundle_path)
+			.and_then(|mut bundle| bundle.load_presentation().map(|_| ()))
+			.map_err(|error| error.to_string());
+		remove_file(&bundle_path).unwrap();
+
+		let expected_result = Err(ParseError::IncludeInBundle {
+			path: bundle_path.join("intro.sent"),
+		}
+		.to_string());
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
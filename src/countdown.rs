@@ -0,0 +1,119 @@
+//! The countdown shown before a presentation starts, such as while waiting
+//! for a streamed talk to begin.
+
+// Uses
+use std::time::{Duration, Instant};
+
+use breeze::date::{SECONDS_PER_DAY, SECONDS_PER_MINUTE};
+
+// Constants
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+/// The longest a countdown can last, which keeps its end time representable.
+const MAXIMUM_DURATION: Duration = Duration::from_secs(100 * 365 * SECONDS_PER_DAY);
+
+/// A countdown to the start of the presentation, which ticks every second.
+#[derive(Clone, Copy, Debug)]
+pub struct Countdown {
+	end_time: Instant,
+}
+
+impl Countdown {
+	/// Starts a countdown that lasts for the provided duration, up to a
+	/// hundred years.
+	pub fn start(duration: Duration) -> Self {
+		Self {
+			end_time: Instant::now() + duration.min(MAXIMUM_DURATION),
+		}
+	}
+
+	/// Ends the countdown early.
+	pub fn skip(&mut self) {
+		self.end_time = Instant::now();
+	}
+
+	pub fn is_finished(&self) -> bool {
+		self.end_time <= Instant::now()
+	}
+
+	/// The text of the countdown slide, showing the time remaining.
+	pub fn text(&self) -> String {
+		countdown_text(self.end_time.saturating_duration_since(Instant::now()))
+	}
+
+	/// When the time remaining next changes, so that the slide can be redrawn.
+	pub fn next_tick_time(&self) -> Instant {
+		let now = Instant::now();
+
+		now + time_until_next_tick(self.end_time.saturating_duration_since(now))
+	}
+}
+
+/// Formats the time remaining as `m:ss`, rounding up so that the countdown
+/// only shows `0:00` once it has finished.
+fn countdown_text(remaining: Duration) -> String {
+	let mut seconds = remaining.as_secs();
+	if remaining.subsec_nanos() > 0 {
+		seconds += 1;
+	}
+
+	format!(
+		"Starting in {}:{:02}",
+		seconds / SECONDS_PER_MINUTE,
+		seconds % SECONDS_PER_MINUTE
+	)
+}
+
+/// How long until the rounded-up time remaining changes.
+fn time_until_next_tick(remaining: Duration) -> Duration {
+	let fraction = Duration::from_nanos(u64::from(remaining.subsec_nanos()));
+
+	if fraction.is_zero() {
+		TICK_INTERVAL.min(remaining)
+	} else {
+		fraction
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::time::Duration;
+
+	use super::{countdown_text, time_until_next_tick};
+
+	#[test]
+	fn text() {
+		let actual_result = [
+			countdown_text(Duration::from_secs(300)),
+			countdown_text(Duration::from_millis(299_500)),
+			countdown_text(Duration::from_millis(1)),
+			countdown_text(Duration::ZERO),
+		];
+
+		let expected_result = [
+			"Starting in 5:00".to_owned(),
+			"Starting in 5:00".to_owned(),
+			"Starting in 0:01".to_owned(),
+			"Starting in 0:00".to_owned(),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn ticks() {
+		let actual_result = [
+			time_until_next_tick(Duration::from_secs(300)),
+			time_until_next_tick(Duration::from_millis(299_250)),
+			time_until_next_tick(Duration::ZERO),
+		];
+
+		let expected_result = [
+			Duration::from_secs(1),
+			Duration::from_millis(250),
+			Duration::ZERO,
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
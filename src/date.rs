@@ -0,0 +1,56 @@
+//! Calendar and clock calculations, for showing times as dates and reading
+//! durations without depending on a date library.
+
+// Uses
+use std::time::Duration;
+
+// Constants
+pub const SECONDS_PER_MINUTE: u64 = 60;
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * SECONDS_PER_MINUTE;
+const TIME_SEPARATOR: char = ':';
+
+/// Converts a number of days since the Unix epoch to a year, month, and day
+/// in the proleptic Gregorian calendar.
+///
+/// This is Howard Hinnant's `civil_from_days` algorithm, limited to dates
+/// after the epoch.
+#[must_use]
+pub fn civil_date(days_since_epoch: u64) -> (u64, u64, u64) {
+	const DAYS_PER_ERA: u64 = 146_097;
+
+	// Eras are 400-year cycles, starting on the 1st of March in year 0
+	let days = days_since_epoch + 719_468;
+	let era = days / DAYS_PER_ERA;
+	let day_of_era = days % DAYS_PER_ERA;
+	let year_of_era =
+		(day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	// Months start from March, so that leap days are at the end of the year
+	let shifted_month = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+	let month = if shifted_month < 10 {
+		shifted_month + 3
+	} else {
+		shifted_month - 9
+	};
+	let year = era * 400 + year_of_era + u64::from(month <= 2);
+
+	(year, month, day)
+}
+
+/// Parses a duration in the format `mm:ss`, where the minutes can be any
+/// number and the seconds must be less than a minute.
+#[must_use]
+pub fn parse_minutes_and_seconds(value: &str) -> Option<Duration> {
+	let (minutes, seconds) = value.split_once(TIME_SEPARATOR)?;
+	let minutes = minutes.parse::<u64>().ok()?;
+	let seconds = seconds
+		.parse::<u64>()
+		.ok()
+		.filter(|&seconds| seconds < SECONDS_PER_MINUTE)?;
+	let total_seconds = minutes
+		.checked_mul(SECONDS_PER_MINUTE)?
+		.checked_add(seconds)?;
+
+	Some(Duration::from_secs(total_seconds))
+}
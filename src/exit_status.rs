@@ -0,0 +1,61 @@
+//! The exit statuses of the program, so that scripts can tell failures apart.
+//!
+//! The codes are part of the command-line interface, so they must never
+//! change.
+
+// Uses
+use std::process::ExitCode;
+
+/// The category of a failure, which determines the exit code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExitStatus {
+	/// The presentation couldn't be displayed, or some other unexpected
+	/// failure occurred.
+	Failure,
+	/// The command-line arguments were invalid.
+	Usage,
+	/// A presentation file couldn't be read, or referred to something that
+	/// doesn't exist.
+	Presentation,
+	/// An image or video used by the presentation couldn't be loaded.
+	Media,
+}
+
+impl ExitStatus {
+	/// The exit code for the status.
+	pub fn code(self) -> u8 {
+		match self {
+			Self::Failure => 1,
+			Self::Usage => 2,
+			Self::Presentation => 3,
+			Self::Media => 4,
+		}
+	}
+}
+
+impl From<ExitStatus> for ExitCode {
+	fn from(status: ExitStatus) -> Self {
+		Self::from(status.code())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::ExitStatus;
+
+	#[test]
+	fn stable_codes() {
+		let actual_result = [
+			ExitStatus::Failure,
+			ExitStatus::Usage,
+			ExitStatus::Presentation,
+			ExitStatus::Media,
+		]
+		.map(ExitStatus::code);
+
+		let expected_result = [1, 2, 3, 4];
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
@@ -1,43 +1,172 @@
 // Uses
-use std::{fs::File, io::Read};
+use std::{collections::BTreeSet, fs::File, io::Read};
 
-use gfx_glyph::ab_glyph::FontArc;
+use gfx_glyph::ab_glyph::{Font, FontArc};
 use rust_fontconfig::{FcFontCache, FcPattern, PatternMatch};
 
 /// Loads a font from the system by going through a list of fonts until it
 /// successfully finds & loads one.
-pub fn load_font(font_names: &[&str]) -> Option<FontArc> {
-	// Build the cache
+///
+/// Returns the font along with the index of its name in `font_names`, so that
+/// the caller can tell which of the fonts before it couldn't be loaded.
+#[must_use]
+pub fn load_font(font_names: &[&str]) -> Option<(FontArc, usize)> {
 	let font_cache = FcFontCache::build();
 
-	// Perform the search
-	for font_name in font_names {
-		let font_results = font_cache.query_all(&FcPattern {
-			family: Some((*font_name).to_owned()),
-			bold: PatternMatch::False,
-			italic: PatternMatch::False,
-			..Default::default()
+	select_font(
+		font_names,
+		|font_name| load_named_font(&font_cache, font_name),
+		|| None,
+	)
+}
+
+/// Loads a font from the system as with [`load_font`], falling back to the
+/// first font on the system that can be loaded if none of the listed ones
+/// can.
+///
+/// This is a last resort for when none of the requested fonts are available,
+/// since any font is better than being unable to display anything at all,
+/// even an error. If the fallback is used, the returned index is the length of
+/// `font_names`.
+#[must_use]
+pub fn load_font_or_any(font_names: &[&str]) -> Option<(FontArc, usize)> {
+	let font_cache = FcFontCache::build();
+
+	select_font(
+		font_names,
+		|font_name| load_named_font(&font_cache, font_name),
+		|| load_any_font(&font_cache),
+	)
+}
+
+/// Chooses a font by trying each of the listed fonts in order, then the
+/// fallback, with the loading itself left to the provided functions.
+///
+/// Returns the font along with the index of its name in `font_names`, or the
+/// length of `font_names` if the fallback was used.
+fn select_font<F, L, A>(
+	font_names: &[&str],
+	mut load_named_font: L,
+	load_fallback_font: A,
+) -> Option<(F, usize)>
+where
+	L: FnMut(&str) -> Option<F>,
+	A: FnOnce() -> Option<F>,
+{
+	font_names
+		.iter()
+		.enumerate()
+		.find_map(|(font_index, font_name)| {
+			load_named_font(font_name).map(|font| (font, font_index))
+		})
+		.or_else(|| load_fallback_font().map(|font| (font, font_names.len())))
+}
+
+/// Loads the regular style of a font family from the font cache.
+fn load_named_font(font_cache: &FcFontCache, font_name: &str) -> Option<FontArc> {
+	let font_results = font_cache.query_all(&FcPattern {
+		family: Some(font_name.to_owned()),
+		bold: PatternMatch::False,
+		italic: PatternMatch::False,
+		..Default::default()
+	});
+
+	font_results
+		.first()
+		.and_then(|font_path| load_font_file(font_path.path.as_str()))
+}
+
+/// Loads the first font in the font cache that can be loaded, regardless of
+/// its name.
+fn load_any_font(font_cache: &FcFontCache) -> Option<FontArc> {
+	// Prefer regular fonts over bold or italic ones
+	let (regular_fonts, other_fonts): (Vec<_>, Vec<_>) =
+		font_cache.list().iter().partition(|(pattern, _)| {
+			pattern.bold != PatternMatch::True && pattern.italic != PatternMatch::True
 		});
 
-		if font_results.is_empty() {
-			continue;
-		}
-
-		// Load the font
-		let font_path = font_results[0];
-		let mut font_bytes = Vec::new();
-		let Ok(mut file) = File::open(font_path.path.as_str()) else {
-			continue;
-		};
-		if file.read_to_end(&mut font_bytes).is_err() {
-			continue;
-		}
-
-		match FontArc::try_from_vec(font_bytes) {
-			Ok(font) => return Some(font),
-			Err(_) => continue,
-		}
+	regular_fonts
+		.into_iter()
+		.chain(other_fonts)
+		.find_map(|(_, font_path)| load_font_file(font_path.path.as_str()))
+}
+
+/// Checks whether a font has a glyph for a character, rather than falling back
+/// to its placeholder glyph.
+#[must_use]
+pub fn has_glyph(font: &FontArc, character: char) -> bool {
+	font.glyph_id(character).0 != 0
+}
+
+/// Loads a font from a file.
+fn load_font_file(path: &str) -> Option<FontArc> {
+	let mut font_bytes = Vec::new();
+	File::open(path).ok()?.read_to_end(&mut font_bytes).ok()?;
+
+	FontArc::try_from_vec(font_bytes).ok()
+}
+
+/// Lists the family names of all fonts available on the system, sorted and
+/// without duplicates.
+#[must_use]
+pub fn list_font_families() -> Vec<String> {
+	let font_cache = FcFontCache::build();
+
+	font_cache
+		.list()
+		.keys()
+		.filter_map(|pattern| pattern.family.clone())
+		.collect::<BTreeSet<_>>()
+		.into_iter()
+		.collect()
+}
+
+/// Parses a list of font names separated by commas or colons, such as from an
+/// environment variable.
+#[must_use]
+pub fn parse_font_list(value: &str) -> Vec<&str> {
+	value
+		.split([',', ':'])
+		.map(str::trim)
+		.filter(|font_name| !font_name.is_empty())
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{parse_font_list, select_font};
+
+	#[test]
+	fn font_list() {
+		let actual_result = parse_font_list("Noto Sans, DejaVu Sans:Liberation Sans,,");
+
+		let expected_result = vec!["Noto Sans", "DejaVu Sans", "Liberation Sans"];
+
+		assert_eq!(expected_result, actual_result);
 	}
 
-	None
+	#[test]
+	fn font_selection() {
+		let font_names = ["Noto Sans", "DejaVu Sans"];
+		let actual_result = [
+			select_font(&font_names, |_| None, || Some("fallback")),
+			select_font(
+				&font_names,
+				|font_name| (font_name == "DejaVu Sans").then_some("DejaVu Sans"),
+				|| Some("fallback"),
+			),
+			select_font(&[], |_| None, || Some("fallback")),
+			select_font(&font_names, |_| None::<&str>, || None),
+		];
+
+		let expected_result = [
+			Some(("fallback", 2)),
+			Some(("DejaVu Sans", 1)),
+			Some(("fallback", 0)),
+			None,
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
 }
@@ -0,0 +1,193 @@
+//! Choosing a video mode for exclusive fullscreen.
+
+// Uses
+use std::{fmt, str::FromStr};
+
+use winit::{
+	monitor::{MonitorHandle, VideoMode},
+	window::Fullscreen,
+};
+
+// Constants
+const SIZE_SEPARATOR: char = 'x';
+const REFRESH_RATE_SEPARATOR: char = '@';
+const MILLIHERTZ_PER_HERTZ: u32 = 1000;
+
+/// A video mode requested on the command line, in the format `WxH@Hz`, where
+/// the refresh rate is optional.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VideoModeRequest {
+	pub width:        u32,
+	pub height:       u32,
+	/// The refresh rate in hertz, or `None` for the highest available.
+	pub refresh_rate: Option<u32>,
+}
+
+/// The properties of a video mode that it's chosen by.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct VideoModeProperties {
+	size:                    (u32, u32),
+	refresh_rate_millihertz: u32,
+	bit_depth:               u16,
+}
+
+impl VideoModeRequest {
+	fn matches(self, properties: VideoModeProperties) -> bool {
+		properties.size == (self.width, self.height)
+			&& self.refresh_rate.map_or(true, |refresh_rate| {
+				// Refresh rates like 59.94 Hz are commonly referred to as 60 Hz
+				(properties.refresh_rate_millihertz + MILLIHERTZ_PER_HERTZ / 2)
+					/ MILLIHERTZ_PER_HERTZ
+					== refresh_rate
+			})
+	}
+}
+
+impl FromStr for VideoModeRequest {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		let (size, refresh_rate) = match value.split_once(REFRESH_RATE_SEPARATOR) {
+			Some((size, refresh_rate)) => (size, Some(refresh_rate)),
+			None => (value, None),
+		};
+		let (width, height) = size.split_once(SIZE_SEPARATOR).ok_or(())?;
+
+		Ok(Self {
+			width:        width.parse().map_err(|_| ())?,
+			height:       height.parse().map_err(|_| ())?,
+			refresh_rate: refresh_rate
+				.map(|refresh_rate| refresh_rate.parse().map_err(|_| ()))
+				.transpose()?,
+		})
+	}
+}
+
+impl fmt::Display for VideoModeRequest {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}{SIZE_SEPARATOR}{}", self.width, self.height)?;
+		if let Some(refresh_rate) = self.refresh_rate {
+			write!(f, "{REFRESH_RATE_SEPARATOR}{refresh_rate}")?;
+		}
+
+		Ok(())
+	}
+}
+
+impl From<&VideoMode> for VideoModeProperties {
+	fn from(video_mode: &VideoMode) -> Self {
+		let size = video_mode.size();
+
+		Self {
+			size:                    (size.width, size.height),
+			refresh_rate_millihertz: video_mode.refresh_rate_millihertz(),
+			bit_depth:               video_mode.bit_depth(),
+		}
+	}
+}
+
+/// Chooses exclusive fullscreen on a monitor, in the requested video mode or
+/// the best one available.
+///
+/// Returns `None` if the monitor has no suitable video mode.
+pub fn exclusive_fullscreen(
+	monitor: &MonitorHandle,
+	request: Option<VideoModeRequest>,
+) -> Option<Fullscreen> {
+	let video_modes = monitor.video_modes().collect::<Vec<_>>();
+	let properties = video_modes
+		.iter()
+		.map(VideoModeProperties::from)
+		.collect::<Vec<_>>();
+
+	choose_video_mode(&properties, request)
+		.map(|index| Fullscreen::Exclusive(video_modes[index].clone()))
+}
+
+/// Chooses the index of the best video mode that matches the request, which
+/// is the one with the most pixels, then the highest refresh rate, then the
+/// highest bit depth.
+fn choose_video_mode(
+	video_modes: &[VideoModeProperties],
+	request: Option<VideoModeRequest>,
+) -> Option<usize> {
+	video_modes
+		.iter()
+		.enumerate()
+		.filter(|(_, properties)| request.map_or(true, |request| request.matches(**properties)))
+		.max_by_key(|(_, properties)| {
+			(
+				u64::from(properties.size.0) * u64::from(properties.size.1),
+				properties.refresh_rate_millihertz,
+				properties.bit_depth,
+			)
+		})
+		.map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::{choose_video_mode, VideoModeProperties, VideoModeRequest};
+
+	// Constants
+	const VIDEO_MODES: &[VideoModeProperties] = &[
+		VideoModeProperties {
+			size:                    (1920, 1080),
+			refresh_rate_millihertz: 59_940,
+			bit_depth:               32,
+		},
+		VideoModeProperties {
+			size:                    (1920, 1080),
+			refresh_rate_millihertz: 144_000,
+			bit_depth:               32,
+		},
+		VideoModeProperties {
+			size:                    (1280, 720),
+			refresh_rate_millihertz: 240_000,
+			bit_depth:               32,
+		},
+	];
+
+	#[test]
+	fn request_parsing() {
+		let actual_result = [
+			"1920x1080@144".parse::<VideoModeRequest>(),
+			"1280x720".parse(),
+			"1280".parse(),
+			"1280x720@fast".parse(),
+		];
+
+		let expected_result = [
+			Ok(VideoModeRequest {
+				width:        1920,
+				height:       1080,
+				refresh_rate: Some(144),
+			}),
+			Ok(VideoModeRequest {
+				width:        1280,
+				height:       720,
+				refresh_rate: None,
+			}),
+			Err(()),
+			Err(()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn video_mode_choice() {
+		let actual_result = [
+			choose_video_mode(VIDEO_MODES, None),
+			choose_video_mode(VIDEO_MODES, "1920x1080@60".parse().ok()),
+			choose_video_mode(VIDEO_MODES, "1280x720".parse().ok()),
+			choose_video_mode(VIDEO_MODES, "800x600".parse().ok()),
+			choose_video_mode(&[], None),
+		];
+
+		let expected_result = [Some(1), Some(0), Some(2), None, None];
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
@@ -0,0 +1,304 @@
+//! A tool for running presentations without fluff. Effectively a spiritual fork
+//! of the `suckless` tool, `sent`.
+//!
+//! The presentation parser and renderer are exposed here so that they can be
+//! embedded in other applications.
+//!
+//! # Example
+//! ```
+//! use breeze::{Presentation, Slide, SlideContent};
+//!
+//! let presentation = Presentation::load(
+//!     "
+//! #.fg:#ffffff
+//!
+//! Hello, world!
+//!
+//! @image.png
+//! ",
+//! );
+//!
+//! assert_eq!(
+//!     presentation.slides,
+//!     vec![
+//!         SlideContent::Text("Hello, world!".to_owned()).into(),
+//!         SlideContent::Image("image.png".to_owned()).into(),
+//!     ]
+//! );
+//! ```
+
+// Linting Rules
+#![warn(
+	clippy::complexity,
+	clippy::correctness,
+	clippy::pedantic,
+	clippy::perf,
+	clippy::style,
+	clippy::suspicious,
+	clippy::clone_on_ref_ptr,
+	clippy::dbg_macro,
+	clippy::decimal_literal_representation,
+	clippy::exit,
+	clippy::filetype_is_file,
+	clippy::if_then_some_else_none,
+	clippy::non_ascii_literal,
+	clippy::self_named_module_files,
+	clippy::str_to_string,
+	clippy::undocumented_unsafe_blocks,
+	clippy::wildcard_enum_match_arm
+)]
+#![allow(
+	clippy::cast_possible_truncation,
+	clippy::cast_possible_wrap,
+	clippy::cast_precision_loss,
+	clippy::cast_sign_loss,
+	clippy::doc_markdown,
+	clippy::module_name_repetitions,
+	clippy::similar_names,
+	clippy::too_many_lines,
+	clippy::unnecessary_wraps,
+	unused_macros
+)]
+
+// Modules
+pub mod bundle;
+pub mod date;
+pub mod fonts;
+pub mod presentation;
+pub mod renderer;
+pub mod video;
+
+// Uses
+use std::{collections::HashMap, path::Path};
+
+use image::{imageops::FilterType, io::Reader as ImageReader, DynamicImage};
+
+use self::video::{probe_video, VideoInfo};
+pub use self::{
+	bundle::Bundle,
+	fonts::load_font,
+	presentation::{ParseError, Presentation, Slide, SlideContent, SlideOptions},
+	renderer::{RenderSettings, Renderer},
+};
+
+// Constants
+pub const USABLE_WIDTH_PERCENTAGE: f32 = 0.75;
+pub const USABLE_HEIGHT_PERCENTAGE: f32 = 0.75;
+pub const DEFAULT_FOREGROUND_COLOUR: LinearRgbaColour = [1.0, 1.0, 1.0, 1.0];
+pub const DEFAULT_BACKGROUND_COLOUR: LinearRgbaColour = [0.0, 0.0, 0.0, 1.0];
+/// The default search list for system fonts, searched in order from top to
+/// bottom, using the first one that's found.
+///
+/// All of these are sans-serif typefaces.
+///
+/// Some of these selections are based on the following article: https://www.ctrl.blog/entry/font-stack-text.html
+pub const DEFAULT_FONT_LIST: &[&str] = &[
+	"Roboto",
+	"Aptos",
+	"Segoe UI",
+	"Noto Sans",
+	"Calibri",
+	"Arial Nova",
+	"Arial",
+	"Helvetica Neue",
+	"Helvetica",
+	"Arimo",
+	"Liberation Sans",
+	"Nimbus Sans",
+	"DejaVu Sans",
+	"Ubuntu",
+];
+/// The minimum scaling factor at which to enable nearest-neighbour image
+/// sampling.
+///
+/// This heuristic matches what [Emulsion] uses.
+///
+/// [Emulsion]: https://github.com/ArturKovacs/emulsion/blob/db5992432ca9f3e0044b967713316ce267e64837/src/widgets/picture_widget.rs#L35
+pub(crate) const IMAGE_SAMPLING_NEAREST_NEIGHBOUR_SCALING_FACTOR_MINIMUM: f32 = 4.0;
+
+// Type Definitions
+/// A colour in linear RGB space, with an alpha channel.
+pub type LinearRgbaColour = [f32; 4];
+
+/// Loads all the images used by a presentation into memory, ready to be passed
+/// to [`Renderer::new`].
+///
+/// Images larger than the presentation's maximum image size are downscaled,
+/// as with [`limit_image_size`].
+///
+/// # Errors
+///
+/// Returns a user-facing error message if any of the images can't be loaded.
+pub fn load_images_from_presentation(
+	presentation: &Presentation,
+) -> Result<HashMap<&String, DynamicImage>, String> {
+	load_images_with(presentation, load_image)
+}
+
+/// Loads all the images used by a presentation from the bundle it was loaded
+/// from, as with [`load_images_from_presentation`].
+///
+/// # Errors
+///
+/// Returns a user-facing error message if any of the images aren't in the
+/// bundle, or can't be loaded.
+pub fn load_images_from_bundle<'a>(
+	presentation: &'a Presentation,
+	bundle: &mut Bundle,
+) -> Result<HashMap<&'a String, DynamicImage>, String> {
+	load_images_with(presentation, |image_path| bundle.load_image(image_path))
+}
+
+/// Loads all the images used by a presentation with the provided function,
+/// downscaling them as necessary.
+fn load_images_with<F>(
+	presentation: &Presentation,
+	mut load_image: F,
+) -> Result<HashMap<&String, DynamicImage>, String>
+where
+	F: FnMut(&str) -> Result<DynamicImage, String>,
+{
+	let mut image_cache = HashMap::new();
+
+	for image_path in presentation
+		.slides
+		.iter()
+		.flat_map(|slide| slide.content.image_paths())
+		.chain(presentation.background_image_paths())
+	{
+		image_cache.insert(
+			image_path,
+			limit_image_size(load_image(image_path)?, presentation.maximum_image_size),
+		);
+	}
+	if let Some(watermark_path) = &presentation.watermark {
+		image_cache.insert(
+			watermark_path,
+			limit_image_size(load_image(watermark_path)?, presentation.maximum_image_size),
+		);
+	}
+
+	Ok(image_cache)
+}
+
+/// Downscales an image so that neither of its dimensions is larger than the
+/// maximum size, keeping its aspect ratio.
+///
+/// Downscaling uses a Lanczos filter with a window of 3, which keeps the image
+/// sharp. Images that are already small enough are left untouched.
+#[must_use]
+pub fn limit_image_size(image: DynamicImage, maximum_size: Option<u32>) -> DynamicImage {
+	match maximum_size {
+		Some(maximum_size) if image.width().max(image.height()) > maximum_size => {
+			image.resize(maximum_size, maximum_size, FilterType::Lanczos3)
+		}
+		_ => image,
+	}
+}
+
+/// Loads a single image into memory.
+///
+/// # Errors
+///
+/// Returns a user-facing error message if the image can't be loaded.
+pub fn load_image(image_path: &str) -> Result<DynamicImage, String> {
+	// The image paths are already resolved relative to their presentation files
+	let resolved_image_path = Path::new(image_path);
+	if resolved_image_path.is_dir() {
+		return Err(format!(
+			"expected an image, but got a directory\n\"{}\"!",
+			resolved_image_path.to_string_lossy()
+		));
+	}
+
+	ImageReader::open(resolved_image_path)
+		.map_err(|_| {
+			format!(
+				"unable to open the image\n\"{}\"!",
+				resolved_image_path.to_string_lossy()
+			)
+		})?
+		.with_guessed_format()
+		.map_err(|_| {
+			format!(
+				"unable to guess the format of the image\n\"{}\"!",
+				resolved_image_path.to_string_lossy()
+			)
+		})?
+		.decode()
+		.map_err(|_| {
+			format!(
+				"unable to load the image\n\"{}\"!",
+				resolved_image_path.to_string_lossy()
+			)
+		})
+}
+
+/// Reads the properties of all the videos used by a presentation, ready to be
+/// passed to [`Renderer::new`].
+///
+/// # Errors
+///
+/// Returns a user-facing error message if any of the videos can't be read.
+pub fn load_videos_from_presentation(
+	presentation: &Presentation,
+) -> Result<HashMap<&String, VideoInfo>, String> {
+	let mut video_info_cache = HashMap::new();
+
+	for video_path in presentation
+		.slides
+		.iter()
+		.filter_map(|slide| match &slide.content {
+			SlideContent::Video(video_path) => Some(video_path),
+			SlideContent::Text(_)
+			| SlideContent::Image(_)
+			| SlideContent::Images(_)
+			| SlideContent::Empty => None,
+		}) {
+		let video_info = probe_video(Path::new(video_path))
+			.map_err(|error| format!("unable to load the video\n\"{video_path}\"!\n({error})"))?;
+
+		video_info_cache.insert(video_path, video_info);
+	}
+
+	Ok(video_info_cache)
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::env::temp_dir;
+
+	use image::DynamicImage;
+
+	use super::{limit_image_size, load_image};
+
+	#[test]
+	fn directory_image_path() {
+		let directory_path = temp_dir();
+		let directory_path = directory_path.to_string_lossy();
+
+		let actual_result = load_image(&directory_path).map(|_| ());
+
+		let expected_result = Err(format!(
+			"expected an image, but got a directory\n\"{directory_path}\"!"
+		));
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn image_size_limit() {
+		let actual_result = [
+			limit_image_size(DynamicImage::new_rgba8(400, 100), Some(200)),
+			limit_image_size(DynamicImage::new_rgba8(100, 400), Some(200)),
+			limit_image_size(DynamicImage::new_rgba8(100, 50), Some(200)),
+			limit_image_size(DynamicImage::new_rgba8(400, 100), None),
+		]
+		.map(|image| (image.width(), image.height()));
+
+		let expected_result = [(200, 50), (50, 200), (100, 50), (400, 100)];
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
@@ -1,5 +1,8 @@
 //! A tool for running presentations without fluff. Effectively a spiritual fork
 //! of the `suckless` tool, `sent`.
+//!
+//! This is a thin wrapper around the library, handling the command line and
+//! the event loop.
 
 // Linting Rules
 #![warn(
@@ -37,278 +40,1055 @@
 #![windows_subsystem = "windows"]
 
 // Modules
-mod fonts;
-mod presentation;
-mod renderer;
+mod arguments;
+mod countdown;
+mod exit_status;
+mod fullscreen;
+mod navigation;
+mod navigation_sound;
+mod screenshot;
+mod shuffle;
+mod sleep_inhibitor;
+mod timings;
 
 // Uses
 use std::{
 	collections::HashMap,
-	env::args,
-	path::{Path, PathBuf},
+	env::{args, var},
+	io::{stderr, stdout, IsTerminal, Write},
+	path::Path,
+	process::{Command, ExitCode, Stdio},
+	thread,
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
-use anyhow::{Context, Result as AnyhowResult};
-use image::{io::Reader as ImageReader, DynamicImage};
+use anyhow::{anyhow, Context, Error as AnyhowError, Result as AnyhowResult};
+use arboard::Clipboard;
+use breeze::{
+	fonts::{list_font_families, load_font_or_any, parse_font_list},
+	limit_image_size,
+	load_image,
+	load_images_from_bundle,
+	load_images_from_presentation,
+	load_videos_from_presentation,
+	presentation::{split_colour_spans, Anchor, Mirror, Output, ThemePreset},
+	video::VideoInfo,
+	Bundle,
+	LinearRgbaColour,
+	Presentation,
+	RenderSettings,
+	Renderer,
+	Slide,
+	SlideContent,
+	DEFAULT_BACKGROUND_COLOUR,
+	DEFAULT_FONT_LIST,
+	DEFAULT_FOREGROUND_COLOUR,
+	USABLE_HEIGHT_PERCENTAGE,
+	USABLE_WIDTH_PERCENTAGE,
+};
+use image::{load_from_memory, DynamicImage};
 use winit::{
-	event::{ElementState, Event, MouseButton, WindowEvent},
+	event::{ElementState, Event, MouseButton, StartCause, Touch, TouchPhase, WindowEvent},
 	event_loop::{ControlFlow, EventLoop},
-	keyboard::{Key, NamedKey},
-	platform::modifier_supplement::KeyEventExtModifierSupplement,
+	keyboard::{Key, ModifiersState, NamedKey},
+	platform::{
+		modifier_supplement::KeyEventExtModifierSupplement,
+		run_on_demand::EventLoopExtRunOnDemand,
+	},
 	window::{Fullscreen, Window, WindowBuilder},
 };
 
 use self::{
-	fonts::load_font,
-	presentation::{Presentation, Slide},
-	renderer::Renderer,
+	arguments::Arguments,
+	countdown::Countdown,
+	exit_status::ExitStatus,
+	navigation::Navigation,
+	navigation_sound::play_navigation_sound,
+	screenshot::save_screenshot,
+	shuffle::random_seed,
+	sleep_inhibitor::SleepInhibitor,
+	timings::load_timings,
 };
 
 // Constants
-const USABLE_WIDTH_PERCENTAGE: f32 = 0.75;
-const USABLE_HEIGHT_PERCENTAGE: f32 = 0.75;
-const DEFAULT_FOREGROUND_COLOUR: LinearRgbaColour = [1.0, 1.0, 1.0, 1.0];
-const DEFAULT_BACKGROUND_COLOUR: LinearRgbaColour = [0.0, 0.0, 0.0, 1.0];
 const ERROR_FOREGROUND_COLOUR: LinearRgbaColour = [1.0, 1.0, 1.0, 1.0];
 const ERROR_BACKGROUND_COLOUR: LinearRgbaColour = [0.01, 0.0, 0.0, 1.0];
-/// The default search list for system fonts, searched in order from top to
-/// bottom, using the first one that's found.
-///
-/// All of these are sans-serif typefaces.
-///
-/// Some of these selections are based on the following article: https://www.ctrl.blog/entry/font-stack-text.html
-const DEFAULT_FONT_LIST: &[&str] = &[
-	"Roboto",
-	"Aptos",
-	"Segoe UI",
-	"Noto Sans",
-	"Calibri",
-	"Arial Nova",
-	"Arial",
-	"Helvetica Neue",
-	"Helvetica",
-	"Arimo",
-	"Liberation Sans",
-	"Nimbus Sans",
-	"DejaVu Sans",
-	"Ubuntu",
-];
+/// Where the screen is split between going back and going forward when it's
+/// tapped, as a fraction of its width from the left.
+const DEFAULT_TOUCH_SPLIT: f32 = 0.5;
+/// The width of text outlines, if not specified.
+const DEFAULT_TEXT_OUTLINE_WIDTH: f32 = 2.0;
+/// Where the watermark is placed, if not specified.
+const DEFAULT_WATERMARK_POSITION: Anchor = Anchor::BottomRight;
 const DEFAULT_TITLE: &str = "`breeze` Presentation";
+/// A presentation covering the basic features, for checking that everything
+/// works without needing a file.
+const DEMO_PRESENTATION: &str = include_str!("../example.sent");
+/// The image used by every image slide of the demo presentation.
+const DEMO_IMAGE: &[u8] = include_bytes!("../logo.png");
+/// Appended to errors when there's no terminal, so that it's clear how to
+/// dismiss them.
+const DETACHED_ERROR_HINT: &str = "\n\n(press Escape to close)";
 
-const FULLSCREEN_VALUE: Fullscreen = Fullscreen::Borderless(None);
-/// The minimum scaling factor at which to enable nearest-neighbour image
-/// sampling.
-///
-/// This heuristic matches what [Emulsion] uses.
-///
-/// [Emulsion]: https://github.com/ArturKovacs/emulsion/blob/db5992432ca9f3e0044b967713316ce267e64837/src/widgets/picture_widget.rs#L35
-const IMAGE_SAMPLING_NEAREST_NEIGHBOUR_SCALING_FACTOR_MINIMUM: f32 = 4.0;
+/// Font names to search before the default list, separated by commas or
+/// colons.
+const FONTS_ENVIRONMENT_VARIABLE: &str = "BREEZE_FONTS";
 
-// Type Definitions
-type LinearRgbaColour = [f32; 4];
+const FULLSCREEN_VALUE: Fullscreen = Fullscreen::Borderless(None);
 
 // Entry Point
-fn main() -> AnyhowResult<()> {
-	const FILE_PATH_ARGUMENT_INDEX: usize = 1;
-	const EXPECTED_ARGUMENT_COUNT: usize = FILE_PATH_ARGUMENT_INDEX + 1;
+fn main() -> ExitCode {
+	match run() {
+		Ok(()) => ExitCode::SUCCESS,
+		Err((error, exit_status)) => {
+			eprintln!("Error: {error:?}");
+			exit_status.into()
+		}
+	}
+}
+
+/// Runs the program, returning the error along with the exit status it should
+/// result in if it fails.
+fn run() -> Result<(), (AnyhowError, ExitStatus)> {
+	const ARGUMENTS_START_INDEX: usize = 1;
 
 	let user_error;
+	let user_error_status;
+	let mut is_non_gui_mode = false;
+	// Without a terminal, such as when launched from a file manager, anything
+	// written to the command line is invisible
+	let is_detached = !stderr().is_terminal();
+	// The event loop can only be created once, so it's shared with the error
+	// presentation in case the presentation fails after creating it
+	let mut event_loop = None;
 
 	'user_error_block: {
-		// Read the file path from the command line
-		let args = args().collect::<Vec<_>>();
-		if args.len() < EXPECTED_ARGUMENT_COUNT {
-			user_error = "you must run this program with a file!".to_owned();
-			break 'user_error_block;
+		// Read the arguments from the command line
+		let arguments = match Arguments::parse(args().skip(ARGUMENTS_START_INDEX)) {
+			Ok(arguments) => arguments,
+			Err(error) => {
+				user_error = error;
+				user_error_status = ExitStatus::Usage;
+				break 'user_error_block;
+			}
+		};
+		is_non_gui_mode = arguments.is_non_gui_mode();
+
+		// Listing the fonts doesn't need a presentation
+		if arguments.list_fonts {
+			// Stop quietly if the output is closed early, such as when piped to `head`
+			let mut output = stdout().lock();
+			for font_family in list_font_families() {
+				if writeln!(output, "{font_family}").is_err() {
+					break;
+				}
+			}
+
+			return Ok(());
 		}
-		if args.len() > EXPECTED_ARGUMENT_COUNT {
-			user_error = "this program expects only one argument!".to_owned();
+
+		if arguments.file_paths.is_empty() && arguments.manifest.is_none() && !arguments.demo {
+			user_error = "you must run this program with a file!".to_owned();
+			user_error_status = ExitStatus::Usage;
 			break 'user_error_block;
 		}
-		let file_path = PathBuf::from(&args[FILE_PATH_ARGUMENT_INDEX]);
 
-		// Load the presentation
-		let presentation = match Presentation::load_from_path(file_path.clone()) {
-			Ok(presentation) => presentation,
+		// Load the presentation, concatenating the files if there are multiple
+		let (mut presentation, mut bundle) = match load_presentation(&arguments) {
+			Ok(loaded) => loaded,
 			Err(error) => {
 				user_error = error;
+				user_error_status = ExitStatus::Presentation;
 				break 'user_error_block;
 			}
 		};
 
+		// The window is what the audience sees, so leave out the slides meant for
+		// other outputs
+		presentation.retain_slides_for(Output::Audience);
+		// A forced theme replaces all of the presentation's colours
+		if !(arguments.force_theme && arguments.theme.is_some()) {
+			presentation.cycle_foreground_colours();
+		}
+		presentation.substitute_placeholders(
+			SystemTime::now()
+				.duration_since(UNIX_EPOCH)
+				.map_or(0, |duration| duration.as_secs()),
+		);
+
+		// Make sure the starting slide exists before going any further
+		if let Some(anchor) = &arguments.goto {
+			if presentation.find_anchor(anchor).is_none() {
+				user_error = format!("there is no slide with the anchor \"{anchor}\"!");
+				user_error_status = ExitStatus::Presentation;
+				break 'user_error_block;
+			}
+		}
+
+		// Listing the slides only needs the presentation to be parsed
+		if arguments.list_slides {
+			let mut output = stdout().lock();
+			for (slide_index, slide) in presentation.slides.iter().enumerate() {
+				if writeln!(output, "{}: {}", slide_index + 1, slide.content.summarise()).is_err() {
+					break;
+				}
+			}
+
+			return Ok(());
+		}
+
 		// Load all images into memory
-		let base_path = file_path.parent();
-		let image_cache = match load_images_from_presentation(&presentation, base_path) {
+		let image_cache = match if arguments.demo {
+			load_demo_images(&presentation)
+		} else if let Some(bundle) = &mut bundle {
+			load_images_from_bundle(&presentation, bundle)
+		} else {
+			load_images_from_presentation(&presentation)
+		} {
 			Ok(image_cache) => image_cache,
 			Err(error) => {
 				user_error = error;
+				user_error_status = ExitStatus::Media;
 				break 'user_error_block;
 			}
 		};
 
+		// Read the properties of all videos, so that any problems are caught early
+		let video_info_cache = match load_videos_from_presentation(&presentation) {
+			Ok(video_info_cache) => video_info_cache,
+			Err(error) => {
+				user_error = error;
+				user_error_status = ExitStatus::Media;
+				break 'user_error_block;
+			}
+		};
+
+		// Load the times to advance the slides at, if there are any
+		let timings = match arguments.timings.as_deref().map(load_timings).transpose() {
+			Ok(timings) => timings.unwrap_or_default(),
+			Err(error) => {
+				user_error = error;
+				user_error_status = ExitStatus::Presentation;
+				break 'user_error_block;
+			}
+		};
+
+		// Everything has loaded successfully, so validation is complete
+		// This is checked before the display backend is ever initialised, so that it
+		// works in environments without one
+		if arguments.validate {
+			return Ok(());
+		}
+
 		// Run the presentation
-		run_presentation(&presentation, image_cache)?;
+		// When detached, a failure is shown using the presentation interface instead
+		// of silently exiting
+		if let Err(error) = run_presentation(
+			&mut event_loop,
+			&arguments,
+			&presentation,
+			image_cache,
+			video_info_cache,
+			timings,
+		) {
+			if !is_detached {
+				return Err((error, ExitStatus::Failure));
+			}
+			user_error = format!("{error:#}");
+			user_error_status = ExitStatus::Failure;
+			break 'user_error_block;
+		}
 		return Ok(());
 	}
 
+	// Without a window, the error can only be reported on the command line
+	// When detached, nothing would see it there, so it's displayed regardless
+	if is_non_gui_mode && !is_detached {
+		return Err((anyhow!(user_error), user_error_status));
+	}
+
 	// If there was some sort of user error, display it using the presentation
 	// interface
-	let mut error_presentation = Presentation::from(user_error);
+	// If that fails too, the original error is reported alongside the reason
+	// Either way, the exit status still reflects the original error
+	let mut error_text = user_error.clone();
+	if is_detached {
+		error_text.push_str(DETACHED_ERROR_HINT);
+	}
+	let mut error_presentation = Presentation::from(error_text);
 	error_presentation.foreground_colour = Some(ERROR_FOREGROUND_COLOUR);
 	error_presentation.background_colour = Some(ERROR_BACKGROUND_COLOUR);
-	run_presentation(&error_presentation, HashMap::new())?;
-
-	Ok(())
-}
-
-fn load_images_from_presentation<'a>(
-	presentation: &'a Presentation,
-	base_path: Option<&Path>,
-) -> Result<HashMap<&'a String, DynamicImage>, String> {
-	let mut image_cache = HashMap::new();
-
-	for image_path in presentation.slides.iter().filter_map(|slide| match slide {
-		Slide::Image(image_path) => Some(image_path),
-		Slide::Text(_) | Slide::Empty => None,
-	}) {
-		// Resolve the image path relative to the presentation file
-		let resolved_image_path = if let Some(base_path) = base_path {
-			base_path.to_owned().join(image_path)
-		} else {
-			PathBuf::from(image_path)
-		};
-
-		// Load the image into memory
-		let image = ImageReader::open(resolved_image_path.as_path())
-			.map_err(|_| {
-				format!(
-					"unable to open the image\n\"{}\"!",
-					resolved_image_path.to_string_lossy()
-				)
-			})?
-			.with_guessed_format()
-			.map_err(|_| {
-				format!(
-					"unable to guess the format of the image\n\"{}\"!",
-					resolved_image_path.to_string_lossy()
-				)
-			})?
-			.decode()
-			.map_err(|_| {
-				format!(
-					"unable to load the image\n\"{}\"!",
-					resolved_image_path.to_string_lossy()
-				)
-			})?;
-
-		image_cache.insert(image_path, image);
-	}
+	run_presentation(
+		&mut event_loop,
+		&Arguments::default(),
+		&error_presentation,
+		HashMap::new(),
+		HashMap::new(),
+		Vec::new(),
+	)
+	.with_context(|| user_error.clone())
+	.map_err(|error| (error, user_error_status))?;
 
-	Ok(image_cache)
+	Err((anyhow!(user_error), user_error_status))
 }
 
+/// Runs a presentation until its window is closed.
+///
+/// The event loop is created the first time a presentation is run, and
+/// reused after that.
 fn run_presentation(
+	event_loop: &mut Option<EventLoop<()>>,
+	arguments: &Arguments,
 	presentation: &Presentation,
 	image_cache: HashMap<&String, DynamicImage>,
+	video_info_cache: HashMap<&String, VideoInfo>,
+	mut timings: Vec<Duration>,
 ) -> AnyhowResult<()> {
-	let window_title = presentation
-		.try_get_title()
-		.unwrap_or_else(|| DEFAULT_TITLE.to_owned());
-
 	// Load the font to use for rendering text
 	// The user font list is extended with the default list so that there's a
 	// fallback in case none of the user fonts can be found
+	// The environment can add to the start of the default list, for systems where
+	// it isn't appropriate
 	let mut font_list = presentation
 		.font_list
 		.iter()
 		.map(String::as_str)
 		.collect::<Vec<_>>();
+	let user_font_count = font_list.len();
+	let environment_font_list = var(FONTS_ENVIRONMENT_VARIABLE).unwrap_or_default();
+	font_list.extend(parse_font_list(environment_font_list.as_str()));
 	font_list.extend_from_slice(DEFAULT_FONT_LIST);
-	let font = load_font(font_list.as_slice())
-		.with_context(|| "unable to find & load any font in the provided list")?;
+	// As a last resort, any font on the system is used, so that even errors can
+	// still be displayed
+	let (font, font_index) = load_font_or_any(font_list.as_slice())
+		.with_context(|| "unable to find & load any font on the system")?;
 
-	// Prepare the colours to use
-	let foreground_colour = presentation
-		.foreground_colour
-		.unwrap_or(DEFAULT_FOREGROUND_COLOUR);
-	let background_colour = presentation
-		.background_colour
-		.unwrap_or(DEFAULT_BACKGROUND_COLOUR);
+	// Warn about any user fonts that couldn't be used, since otherwise the
+	// fallback is silent
+	let font_warning = (font_index > 0 && user_font_count > 0).then(|| {
+		let missing_fonts = font_list[..font_index.min(user_font_count)]
+			.iter()
+			.map(|font_name| format!("\"{font_name}\""))
+			.collect::<Vec<_>>()
+			.join(", ");
+		let fallback_font = font_list.get(font_index).map_or_else(
+			|| "another system font".to_owned(),
+			|font_name| format!("\"{font_name}\""),
+		);
+		format!("Unable to load the font(s) {missing_fonts} - using {fallback_font} instead.")
+	});
+	if let Some(font_warning) = font_warning.as_ref().filter(|_| !arguments.quiet) {
+		eprintln!("warning: {font_warning}");
+	}
+
+	// Presentations without any text, such as photo decks, are named after their
+	// file instead
+	let window_title = presentation
+		.try_get_title()
+		.or_else(|| presentation_file_name(arguments))
+		.unwrap_or_else(|| DEFAULT_TITLE.to_owned());
+
+	// Collect the themes that can be switched between, the first of which takes
+	// precedence over the base colours
+	// A forced theme preset replaces all of the presentation's colours
+	let preset_theme = arguments.theme.map(ThemePreset::to_theme);
+	let is_theme_forced = arguments.force_theme && preset_theme.is_some();
+	let (foreground_colour, background_colour, themes) = if is_theme_forced {
+		(None, None, Vec::new())
+	} else {
+		(
+			presentation.foreground_colour,
+			presentation.background_colour,
+			[presentation.dark_theme, presentation.light_theme]
+				.into_iter()
+				.flatten()
+				.collect::<Vec<_>>(),
+		)
+	};
+
+	// Prepare the settings to render with
+	let mut render_settings = RenderSettings {
+		foreground_colour:     foreground_colour
+			.or(preset_theme.map(|theme| theme.foreground_colour))
+			.unwrap_or(DEFAULT_FOREGROUND_COLOUR),
+		background_colour:     background_colour
+			.or(preset_theme.map(|theme| theme.background_colour))
+			.unwrap_or(DEFAULT_BACKGROUND_COLOUR),
+		stage_colour:          presentation.stage_colour,
+		blank_colour:          presentation.blank_colour,
+		fit_mode:              presentation.fit_mode.unwrap_or_default(),
+		fit_overflow:          presentation.fit_overflow.unwrap_or_default(),
+		animations_enabled:    !arguments.no_animation && presentation.animations.unwrap_or(true),
+		mirror:                if arguments.mirror {
+			Some(Mirror::Horizontal)
+		} else {
+			presentation.mirror
+		},
+		opacity:               presentation.opacity.unwrap_or(1.0),
+		text_outline_colour:   presentation.text_outline_colour,
+		text_outline_width:    presentation
+			.text_outline_width
+			.unwrap_or(DEFAULT_TEXT_OUTLINE_WIDTH),
+		hanging_indents:       presentation.bullets.unwrap_or(false),
+		// The margins are on both sides of the usable space
+		usable_percentages:    (
+			presentation
+				.horizontal_margin
+				.map_or(USABLE_WIDTH_PERCENTAGE, |margin| 1.0 - 2.0 * margin),
+			presentation
+				.vertical_margin
+				.map_or(USABLE_HEIGHT_PERCENTAGE, |margin| 1.0 - 2.0 * margin),
+		),
+		watermark:             presentation.watermark.clone(),
+		watermark_position:    presentation
+			.watermark_position
+			.unwrap_or(DEFAULT_WATERMARK_POSITION),
+		watermark_opacity:     presentation.watermark_opacity.unwrap_or(1.0),
+		show_slide_numbers:    presentation.show_slide_numbers.unwrap_or(false),
+		show_progress_bar:     presentation.show_progress_bar.unwrap_or(false),
+		vsync:                 arguments.vsync.unwrap_or(true),
+		text_rendering:        presentation.text_rendering.unwrap_or_default(),
+		default_slide_options: presentation.default_slide_options.clone(),
+	};
+	if let Some(initial_theme) = themes.first() {
+		render_settings.foreground_colour = initial_theme.foreground_colour;
+		render_settings.background_colour = initial_theme.background_colour;
+	}
 
 	// Initialise the event loop and renderer
-	let event_loop =
-		EventLoop::new().with_context(|| "unable to initialise the display backend")?;
+	let event_loop = match event_loop {
+		Some(event_loop) => event_loop,
+		None => {
+			event_loop.insert(EventLoop::new().with_context(|| "no display backend is available")?)
+		}
+	};
 	event_loop.set_control_flow(ControlFlow::Wait);
+
+	// Exclusive fullscreen falls back to borderless fullscreen if the monitor
+	// doesn't support it
+	let is_exclusive_fullscreen = arguments.exclusive_fullscreen || arguments.video_mode.is_some();
+	let exclusive_fullscreen = is_exclusive_fullscreen
+		.then(|| {
+			event_loop
+				.primary_monitor()
+				.or_else(|| event_loop.available_monitors().next())
+		})
+		.flatten()
+		.and_then(|monitor| fullscreen::exclusive_fullscreen(&monitor, arguments.video_mode));
+	let fullscreen_warning =
+		(is_exclusive_fullscreen && exclusive_fullscreen.is_none()).then(|| {
+			arguments.video_mode.map_or_else(
+				|| {
+					"Exclusive fullscreen isn't available - using borderless fullscreen instead."
+						.to_owned()
+				},
+				|video_mode| {
+					format!(
+						"The video mode {video_mode} isn't available - using borderless \
+						 fullscreen instead."
+					)
+				},
+			)
+		});
+	if let Some(fullscreen_warning) = fullscreen_warning.as_ref().filter(|_| !arguments.quiet) {
+		eprintln!("warning: {fullscreen_warning}");
+	}
+	let fullscreen = exclusive_fullscreen.unwrap_or(FULLSCREEN_VALUE);
+
 	let window_builder = WindowBuilder::new()
 		.with_title(window_title)
 		.with_resizable(true)
-		.with_fullscreen(Some(FULLSCREEN_VALUE));
+		.with_fullscreen(Some(fullscreen.clone()));
 
 	let mut renderer = Renderer::new(
-		&event_loop,
+		event_loop,
 		window_builder,
 		|window| window.set_cursor_visible(false),
 		font,
-		foreground_colour,
-		background_colour,
+		render_settings,
 		image_cache,
+		video_info_cache,
 	)
-	.with_context(|| "unable to initialise the renderer")?;
+	.with_context(|| "unable to initialise the renderer - no usable OpenGL context available")?;
+	// Each cue advances to the next slide, so there can't be more cues than slides
+	// after the first, not counting backup slides
+	let main_slide_count = presentation
+		.slides
+		.iter()
+		.filter(|slide| !slide.is_backup(&presentation.default_slide_options))
+		.count();
+	let ignored_cue_count = timings
+		.len()
+		.saturating_sub(main_slide_count.saturating_sub(1));
+	timings.truncate(timings.len() - ignored_cue_count);
+	let timings_warning = (ignored_cue_count > 0).then(|| {
+		format!(
+			"The timings file has {ignored_cue_count} more cue(s) than there are slides to \
+			 advance to - ignoring them."
+		)
+	});
+	if let Some(timings_warning) = timings_warning.as_ref().filter(|_| !arguments.quiet) {
+		eprintln!("warning: {timings_warning}");
+	}
+
+	// The presentation starts fullscreen, which is when sleep is inhibited
+	let mut sleep_inhibitor = SleepInhibitor::new(!arguments.no_inhibit_sleep);
+	let sleep_warning = (!sleep_inhibitor.set_inhibited(true))
+		.then(|| "Unable to keep the system from sleeping.".to_owned());
+	if let Some(sleep_warning) = sleep_warning.as_ref().filter(|_| !arguments.quiet) {
+		eprintln!("warning: {sleep_warning}");
+	}
+
+	for warning in [
+		font_warning,
+		fullscreen_warning,
+		timings_warning,
+		sleep_warning,
+	]
+	.into_iter()
+	.flatten()
+	{
+		renderer.show_notification(warning);
+	}
 
 	// Runtime State
 	let mut is_fullscreen = true;
-	let mut current_slide = 0;
+	// In kiosk mode, only a deliberate key combination can close the presentation
+	let is_kiosk = arguments.kiosk || presentation.kiosk.unwrap_or(false);
+	let mut modifiers = ModifiersState::empty();
+	// The presentation returns to the first slide after a period without input
+	let idle_reset_duration = presentation.idle_reset;
+	let mut last_input_time = Instant::now();
+	// Slides with an advance duration move on by themselves once it has passed
+	// since the last advance or input, whichever was later
+	let mut last_advance_time = Instant::now();
+	let mut navigation = Navigation::with_steps(
+		presentation
+			.slides
+			.iter()
+			.map(|slide| slide.reveal_step_count(&presentation.default_slide_options))
+			.collect(),
+		presentation.looping.unwrap_or(false),
+	)
+	.with_backup_slides(
+		presentation
+			.slides
+			.iter()
+			.map(|slide| slide.is_backup(&presentation.default_slide_options))
+			.collect(),
+	);
+	if arguments.shuffle || presentation.shuffle.unwrap_or(false) {
+		navigation.shuffle(arguments.seed.unwrap_or_else(random_seed));
+	}
+	if let Some(start_slide) = arguments
+		.goto
+		.as_deref()
+		.and_then(|anchor| presentation.find_anchor(anchor))
+	{
+		navigation.go_to(start_slide);
+	}
+	// The slide that is being typed in to go to, if any
+	let mut goto_input: Option<String> = None;
+	// The text that is being typed in to search for, if any, along with the slide
+	// the search started from
+	let mut search_input: Option<(String, usize)> = None;
+	// The last text that was searched for, for finding its other matches
+	let mut last_search: Option<String> = None;
+	let mut current_theme = 0;
+	// Whether the screen is temporarily blanked, hiding the current slide
+	let mut is_blanked = false;
+	// The clipboard is only initialised once it's needed, and is kept around
+	// afterwards since on some platforms the copied contents only last as long as
+	// it does
+	let mut clipboard: Option<Option<Clipboard>> = None;
+	// The countdown is shown before the first slide, unless the presentation
+	// starts somewhere else
+	let mut countdown = presentation
+		.countdown
+		.filter(|_| arguments.goto.is_none())
+		.map(Countdown::start);
+	// The cues from the timings file are measured from the start of the
+	// presentation, which is after the countdown
+	let mut start_time = Instant::now();
+	let mut next_cue = 0;
+	// The sound is played when a different slide is shown, however it was
+	// navigated to
+	let mut is_navigation_sound_enabled =
+		arguments.navigation_sound || presentation.navigation_sound.unwrap_or(false);
+	let mut last_shown_slide = navigation.current_slide();
 
 	#[allow(clippy::wildcard_enum_match_arm, clippy::single_match)]
 	event_loop
-		.run(move |event, window_target| {
+		.run_on_demand(move |event, window_target| {
 			let window = renderer.get_window();
 
+			if let Event::WindowEvent {
+				event:
+					WindowEvent::KeyboardInput { .. }
+					| WindowEvent::MouseInput { .. }
+					| WindowEvent::MouseWheel { .. }
+					| WindowEvent::Touch(_),
+				..
+			} = &event
+			{
+				last_input_time = Instant::now();
+			}
+
+			if countdown.as_ref().is_some_and(Countdown::is_finished) {
+				countdown = None;
+				start_time = Instant::now();
+				last_advance_time = start_time;
+				window.request_redraw();
+			}
+
+			// The presentation doesn't advance by itself during the countdown or while the
+			// screen is blanked
+			let advance_time = presentation.slides[navigation.current_slide()]
+				.advance_duration(&presentation.default_slide_options)
+				.filter(|_| countdown.is_none() && !is_blanked)
+				.and_then(|duration| last_advance_time.max(last_input_time).checked_add(duration));
+
 			match event {
+				Event::NewEvents(StartCause::ResumeTimeReached { .. }) => {
+					// Cues go to a specific position in the presentation's normal flow, so that
+					// manually changing slides in between doesn't throw off the rest of the timings
+					let first_cue = next_cue;
+					while countdown.is_none()
+						&& timings
+							.get(next_cue)
+							.and_then(|&cue| start_time.checked_add(cue))
+							.is_some_and(|cue_time| cue_time <= Instant::now())
+					{
+						next_cue += 1;
+						navigation.go_to_position(next_cue);
+						last_advance_time = Instant::now();
+					}
+					// There's nothing to advance to on the last slide, so it's only tried again
+					// after another full duration
+					if next_cue == first_cue
+						&& advance_time.is_some_and(|advance_time| advance_time <= Instant::now())
+					{
+						last_advance_time = Instant::now();
+						navigation.step(true);
+					}
+					if idle_reset_duration
+						.and_then(|duration| {
+							idle_reset_time(last_input_time, last_advance_time, duration)
+						})
+						.is_some_and(|idle_reset_time| idle_reset_time <= Instant::now())
+					{
+						navigation.go_to_start();
+					}
+					window.request_redraw();
+				}
+				Event::AboutToWait => {
+					// Wake up for the next scheduled redraw, idle reset, cue, or advance, if there
+					// is one
+					let idle_reset_time = idle_reset_duration
+						.filter(|_| !navigation.is_at_start())
+						.and_then(|duration| {
+							idle_reset_time(last_input_time, last_advance_time, duration)
+						});
+					// The cues only start once the countdown has finished
+					let cue_time = timings
+						.get(next_cue)
+						.filter(|_| countdown.is_none())
+						.and_then(|&cue| start_time.checked_add(cue));
+					let countdown_tick_time = countdown.as_ref().map(Countdown::next_tick_time);
+					if let Some(next_wake_time) = renderer
+						.get_next_redraw_time()
+						.into_iter()
+						.chain(idle_reset_time)
+						.chain(cue_time)
+						.chain(advance_time)
+						.chain(countdown_tick_time)
+						.min()
+					{
+						window_target.set_control_flow(ControlFlow::WaitUntil(next_wake_time));
+					} else {
+						window_target.set_control_flow(ControlFlow::Wait);
+					}
+				}
 				Event::WindowEvent { event, .. } => match event {
-					WindowEvent::CloseRequested => window_target.exit(),
+					WindowEvent::CloseRequested => {
+						if !is_kiosk {
+							window_target.exit();
+						}
+					}
+					WindowEvent::ModifiersChanged(new_modifiers) => {
+						modifiers = new_modifiers.state();
+					}
 					WindowEvent::Focused(true) => window.request_redraw(),
 					WindowEvent::RedrawRequested => {
-						renderer.render(&presentation.slides[current_slide]);
+						if navigation.current_slide() != last_shown_slide {
+							last_shown_slide = navigation.current_slide();
+							// The sound is turned off if it can't be played, so that the failure
+							// is only reported once
+							if is_navigation_sound_enabled && !play_navigation_sound() {
+								is_navigation_sound_enabled = false;
+								let sound_warning = "Unable to play the navigation sound.";
+								if !arguments.quiet {
+									eprintln!("warning: {sound_warning}");
+								}
+								renderer.show_notification(sound_warning);
+							}
+						}
+
+						let slide = &presentation.slides[navigation.current_slide()];
+						if is_blanked {
+							renderer.render_blank();
+						} else if let Some(countdown) = &countdown {
+							renderer.render(
+								&Slide::from(SlideContent::Text(countdown.text())),
+								None,
+								None,
+							);
+						} else {
+							// Slides shown in a single step are always shown in full
+							let revealed_lines =
+								(slide.reveal_step_count(&presentation.default_slide_options) > 1)
+									.then(|| navigation.current_step() + 1);
+							renderer.render(slide, revealed_lines, navigation.main_position());
+						}
+
+						if let Some(screenshot) = renderer.take_screenshot() {
+							match save_screenshot(&screenshot) {
+								Ok(path) => renderer.show_notification(format!(
+									"Saved a screenshot to \"{}\".",
+									path.to_string_lossy()
+								)),
+								Err(error) => renderer.show_notification(error),
+							}
+						}
+
+						// Move on once a scrolling slide has scrolled past, or scroll it again
+						// if it's the last slide
+						if renderer.is_scroll_finished() {
+							if navigation.step(true) {
+								renderer.get_window().request_redraw();
+							} else {
+								renderer.restart_scroll();
+							}
+						}
+					}
+					WindowEvent::CursorMoved { position, .. } => {
+						renderer.set_cursor_position(position);
 					}
 					WindowEvent::MouseInput {
 						state: ElementState::Pressed,
 						button: MouseButton::Right | MouseButton::Back,
 						..
-					} => change_slides(window, presentation, &mut current_slide, false),
+					} => change_slides(&mut renderer, &mut navigation, &mut countdown, false),
 					WindowEvent::MouseInput {
 						state: ElementState::Pressed,
 						button: MouseButton::Left | MouseButton::Forward,
 						..
-					} => change_slides(window, presentation, &mut current_slide, true),
+					} => change_slides(&mut renderer, &mut navigation, &mut countdown, true),
+					// Tapping to the right of the split goes forward, and to the left goes back
+					WindowEvent::Touch(Touch {
+						phase: TouchPhase::Started,
+						location,
+						..
+					}) => {
+						let split_position = f64::from(window.inner_size().width)
+							* f64::from(presentation.touch_split.unwrap_or(DEFAULT_TOUCH_SPLIT));
+						change_slides(
+							&mut renderer,
+							&mut navigation,
+							&mut countdown,
+							location.x >= split_position,
+						);
+					}
 					WindowEvent::KeyboardInput { event, .. } => {
+						// While typing a slide to go to, keys edit the input instead
+						if let Some(input) = &mut goto_input {
+							if event.state != ElementState::Pressed {
+								return;
+							}
+							match event.key_without_modifiers().as_ref() {
+								Key::Named(NamedKey::Enter) => {
+									// Slides can be gone to by number or by anchor
+									let target_slide = input
+										.parse::<usize>()
+										.ok()
+										.and_then(|slide_number| slide_number.checked_sub(1))
+										.filter(|&slide| slide < presentation.slides.len())
+										.or_else(|| presentation.find_anchor(input));
+									if let Some(target_slide) = target_slide {
+										navigation.go_to(target_slide);
+										renderer.get_window().request_redraw();
+									} else {
+										renderer.show_notification(format!(
+											"There is no slide \"{input}\"."
+										));
+									}
+									goto_input = None;
+								}
+								Key::Named(NamedKey::Escape) => goto_input = None,
+								Key::Named(NamedKey::Backspace) => {
+									input.pop();
+									renderer.show_notification(format!("Go to: {input}"));
+								}
+								_ => {
+									if let Some(text) = event
+										.text
+										.filter(|text| !text.chars().any(char::is_control))
+									{
+										input.push_str(text.as_str());
+										renderer.show_notification(format!("Go to: {input}"));
+									}
+								}
+							}
+							return;
+						}
+
+						// While typing a search, keys edit the query instead, with the first match
+						// being shown as it's typed
+						if let Some((query, start_slide)) = &mut search_input {
+							if event.state != ElementState::Pressed {
+								return;
+							}
+							match event.key_without_modifiers().as_ref() {
+								Key::Named(NamedKey::Enter) => {
+									if !query.is_empty() {
+										last_search = Some(query.clone());
+									}
+									search_input = None;
+								}
+								// Cancelling returns to where the search started
+								Key::Named(NamedKey::Escape) => {
+									if navigation.go_to(*start_slide) {
+										renderer.get_window().request_redraw();
+									}
+									search_input = None;
+								}
+								Key::Named(NamedKey::Backspace) => {
+									query.pop();
+									show_first_match(
+										&mut renderer,
+										&mut navigation,
+										presentation,
+										query,
+										*start_slide,
+									);
+								}
+								_ => {
+									if let Some(text) = event
+										.text
+										.filter(|text| !text.chars().any(char::is_control))
+									{
+										query.push_str(text.as_str());
+										show_first_match(
+											&mut renderer,
+											&mut navigation,
+											presentation,
+											query,
+											*start_slide,
+										);
+									}
+								}
+							}
+							return;
+						}
+
 						if event.state == ElementState::Pressed && !event.repeat {
+							// The help overlay is dismissed by any key
+							if renderer.hide_help() {
+								return;
+							}
+
+							// `?` is typed with modifiers on most layouts, so it's checked for
+							// separately
+							let is_help_key = matches!(
+								event.logical_key.as_ref(),
+								Key::Character("?") | Key::Named(NamedKey::F1)
+							);
+							let is_search_key =
+								matches!(event.logical_key.as_ref(), Key::Character("/"));
+
 							// TODO: Functionality to reload the presentation
 							match event.key_without_modifiers().as_ref() {
+								_ if is_help_key => {
+									renderer.show_help(keybinding_help(arguments.design, is_kiosk));
+								}
+								_ if is_search_key => {
+									search_input =
+										Some((String::new(), navigation.current_slide()));
+									renderer.show_notification("Find: ");
+								}
+								Key::Named(NamedKey::F3) => {
+									if let Some(query) = &last_search {
+										show_next_match(
+											&mut renderer,
+											&mut navigation,
+											presentation,
+											query,
+											!modifiers.shift_key(),
+										);
+									}
+								}
 								Key::Named(NamedKey::Escape) | Key::Character("q") => {
-									window_target.exit();
+									if !is_kiosk || modifiers.control_key() {
+										window_target.exit();
+									}
+								}
+								Key::Named(NamedKey::F11) if !is_kiosk => {
+									toggle_fullscreen(
+										renderer.get_window(),
+										&fullscreen,
+										&mut is_fullscreen,
+										&mut sleep_inhibitor,
+									);
+								}
+								// Presentation clickers send F5 to start the presentation
+								Key::Named(NamedKey::F5) => {
+									if !is_fullscreen {
+										toggle_fullscreen(
+											renderer.get_window(),
+											&fullscreen,
+											&mut is_fullscreen,
+											&mut sleep_inhibitor,
+										);
+									}
+									navigation.go_to_start();
+									is_blanked = false;
+									renderer.get_window().request_redraw();
 								}
-								Key::Named(NamedKey::F11) => {
-									toggle_fullscreen(window, &mut is_fullscreen);
+								Key::Character("b" | ".") => {
+									is_blanked = !is_blanked;
+									renderer.get_window().request_redraw();
+								}
+								Key::Character("t") => {
+									if themes.len() > 1 {
+										current_theme = (current_theme + 1) % themes.len();
+										renderer.set_colours(
+											themes[current_theme].foreground_colour,
+											themes[current_theme].background_colour,
+										);
+									}
+								}
+								Key::Character("g") => {
+									goto_input = Some(String::new());
+									renderer.show_notification("Go to: ");
+								}
+								// Going back through the history is like a web browser
+								Key::Named(NamedKey::ArrowLeft) if modifiers.alt_key() => {
+									if navigation.back() {
+										renderer.get_window().request_redraw();
+									}
+								}
+								Key::Named(NamedKey::BrowserBack) => {
+									if navigation.back() {
+										renderer.get_window().request_redraw();
+									}
+								}
+								// Backup slides are kept out of the way of the normal navigation
+								// keys
+								Key::Character("n") if modifiers.control_key() => {
+									if navigation.go_to_next_backup() {
+										renderer.get_window().request_redraw();
+									} else {
+										renderer
+											.show_notification("There are no other backup slides.");
+									}
+								}
+								Key::Character("s") => renderer.toggle_spotlight(),
+								Key::Named(NamedKey::PrintScreen) => renderer.request_screenshot(),
+								Key::Character("u") if arguments.design => {
+									renderer.toggle_guides();
+								}
+								Key::Character("d") => {
+									renderer.toggle_overlays();
+								}
+								Key::Character("y") => {
+									if let SlideContent::Text(text) =
+										&presentation.slides[navigation.current_slide()].content
+									{
+										// Colour tags aren't part of the text that's shown
+										let text = split_colour_spans(text)
+											.into_iter()
+											.map(|span| span.text)
+											.collect::<String>();
+										let copied = clipboard
+											.get_or_insert_with(|| Clipboard::new().ok())
+											.as_mut()
+											.is_some_and(|clipboard| {
+												clipboard.set_text(text.as_str()).is_ok()
+											});
+
+										renderer.show_notification(if copied {
+											"Copied the slide text."
+										} else {
+											"Unable to copy the slide text."
+										});
+									}
+								}
+								Key::Character("r") => {
+									let image_paths = presentation.slides
+										[navigation.current_slide()]
+									.content
+									.image_paths();
+									if !image_paths.is_empty() {
+										let reloaded = image_paths.iter().all(|image_path| {
+											reload_image(arguments, image_path).is_ok_and(|image| {
+												let image = limit_image_size(
+													image,
+													presentation.maximum_image_size,
+												);
+												renderer.replace_image(image_path, &image).is_ok()
+											})
+										});
+
+										renderer.show_notification(
+											match (reloaded, image_paths.len()) {
+												(true, 1) => "Reloaded the slide image.",
+												(true, _) => "Reloaded the slide images.",
+												(false, 1) => "Unable to reload the slide image.",
+												(false, _) => "Unable to reload the slide images.",
+											},
+										);
+									}
+								}
+								Key::Character("e") if !is_kiosk => {
+									// With multiple files, only the first is opened
+									if let Some(file_path) = arguments.file_paths.first() {
+										if !open_with_default_application(file_path) {
+											renderer.show_notification(
+												"Unable to open the presentation file.",
+											);
+										}
+									}
 								}
 								Key::Named(
 									NamedKey::ArrowLeft
 									| NamedKey::ArrowUp
 									| NamedKey::Backspace
+									| NamedKey::PageUp
 									| NamedKey::NavigatePrevious,
 								)
 								| Key::Character("h" | "k" | "p") => {
-									change_slides(window, presentation, &mut current_slide, false);
+									change_slides(
+										&mut renderer,
+										&mut navigation,
+										&mut countdown,
+										false,
+									);
 								}
 								Key::Named(
 									NamedKey::ArrowRight
 									| NamedKey::ArrowDown
 									| NamedKey::Enter
+									| NamedKey::PageDown
 									| NamedKey::Space
 									| NamedKey::NavigateNext,
 								)
 								| Key::Character("l" | "j" | "n") => {
-									change_slides(window, presentation, &mut current_slide, true);
+									change_slides(
+										&mut renderer,
+										&mut navigation,
+										&mut countdown,
+										true,
+									);
 								}
 								_ => {}
 							}
@@ -322,31 +1102,339 @@ fn run_presentation(
 		.with_context(|| "encountered an error during the event loop")
 }
 
+/// Loads the presentation from the files provided on the command line, along
+/// with the bundle it was loaded from, if it was loaded from one.
+///
+/// The files listed in a manifest come before any others. The built-in demo
+/// presentation is used instead of any files, if requested.
+fn load_presentation(arguments: &Arguments) -> Result<(Presentation, Option<Bundle>), String> {
+	if arguments.demo {
+		return Ok((Presentation::load(DEMO_PRESENTATION), None));
+	}
+
+	// Bundles contain everything their presentation needs, so they're always
+	// loaded on their own
+	if let Some(bundle_path) = arguments
+		.file_paths
+		.iter()
+		.find(|file_path| Bundle::is_bundle_path(file_path))
+	{
+		if arguments.file_paths.len() > 1 || arguments.manifest.is_some() {
+			return Err("a bundle can't be combined with other presentation files!".to_owned());
+		}
+
+		let mut bundle = Bundle::open(bundle_path).map_err(|error| error.to_string())?;
+		let presentation = bundle
+			.load_presentation()
+			.map_err(|error| error.to_string())?;
+		// Videos are played from the filesystem, so they can't be played from inside
+		// a bundle
+		if presentation
+			.slides
+			.iter()
+			.any(|slide| matches!(slide.content, SlideContent::Video(_)))
+		{
+			return Err(format!(
+				"videos can't be played from a bundle\n\"{}\"!",
+				bundle_path.to_string_lossy()
+			));
+		}
+
+		return Ok((presentation, Some(bundle)));
+	}
+
+	let Some(manifest_path) = &arguments.manifest else {
+		return Presentation::load_from_paths(arguments.file_paths.as_slice())
+			.map(|presentation| (presentation, None))
+			.map_err(|error| error.to_string());
+	};
+
+	let mut presentation =
+		Presentation::load_from_manifest(manifest_path).map_err(|error| error.to_string())?;
+	if !arguments.file_paths.is_empty() {
+		presentation.append(
+			Presentation::load_from_paths(arguments.file_paths.as_slice())
+				.map_err(|error| error.to_string())?,
+		);
+	}
+
+	Ok((presentation, None))
+}
+
+/// Loads an image again, such as after it has been edited.
+///
+/// Images from a bundle are loaded from a freshly-opened copy of it, so that
+/// changes to the bundle are picked up.
+fn reload_image(arguments: &Arguments, image_path: &str) -> Result<DynamicImage, String> {
+	match arguments
+		.file_paths
+		.first()
+		.filter(|file_path| Bundle::is_bundle_path(file_path))
+	{
+		Some(bundle_path) => Bundle::open(bundle_path)
+			.map_err(|error| error.to_string())
+			.and_then(|mut bundle| bundle.load_image(image_path)),
+		None => load_image(image_path),
+	}
+}
+
+/// The name of the file the presentation was loaded from, if it was loaded
+/// from files.
+///
+/// The manifest comes first, since its files are loaded before any others.
+fn presentation_file_name(arguments: &Arguments) -> Option<String> {
+	if arguments.demo {
+		return None;
+	}
+
+	arguments
+		.manifest
+		.as_ref()
+		.or(arguments.file_paths.first())
+		.and_then(|path| path.file_name())
+		.map(|file_name| file_name.to_string_lossy().into_owned())
+}
+
+/// Loads the images of the demo presentation, which are all the built-in
+/// sample image.
+fn load_demo_images(presentation: &Presentation) -> Result<HashMap<&String, DynamicImage>, String> {
+	let demo_image = load_from_memory(DEMO_IMAGE)
+		.map_err(|_| "unable to decode the built-in demo image!".to_owned())?;
+
+	Ok(presentation
+		.slides
+		.iter()
+		.flat_map(|slide| slide.content.image_paths())
+		.chain(presentation.background_image_paths())
+		.map(|image_path| (image_path, demo_image.clone()))
+		.collect())
+}
+
+/// Opens a file with the operating system's default application for it,
+/// without waiting for it to close.
+///
+/// Returns whether the application could be launched.
+fn open_with_default_application(file_path: &Path) -> bool {
+	#[cfg(target_os = "windows")]
+	let mut command = {
+		let mut command = Command::new("cmd");
+		command.args(["/C", "start", ""]);
+		command
+	};
+	#[cfg(target_os = "macos")]
+	let mut command = Command::new("open");
+	#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+	let mut command = Command::new("xdg-open");
+
+	command
+		.arg(file_path)
+		.stdin(Stdio::null())
+		.stdout(Stdio::null())
+		.stderr(Stdio::null())
+		.spawn()
+		// Reap the process once it exits, without blocking the event loop
+		.map(|mut child| thread::spawn(move || child.wait()))
+		.is_ok()
+}
+
+/// Lists the keybindings that are available, and their actions, for the help
+/// overlay.
+fn keybinding_help(design: bool, is_kiosk: bool) -> Vec<(&'static str, &'static str)> {
+	let mut keybindings = vec![
+		("Right, Space, l, n", "Next slide"),
+		("Left, Backspace, h, p", "Previous slide"),
+		("F5", "Start from the first slide"),
+		("b, .", "Blank the screen"),
+	];
+	if !is_kiosk {
+		keybindings.push(("F11", "Toggle fullscreen"));
+	}
+	keybindings.extend([
+		("t", "Switch themes"),
+		("g", "Go to a slide"),
+		("/", "Search the slides"),
+		("F3, Shift+F3", "Next or previous match"),
+		("Ctrl+N", "Next backup slide"),
+		("Alt+Left", "Back to the last slide shown"),
+		("d", "Hide or show overlays"),
+		("s", "Toggle the spotlight"),
+		("Print Screen", "Save a screenshot"),
+	]);
+	if design {
+		keybindings.push(("u", "Toggle the design guides"));
+	}
+	keybindings.extend([
+		("y", "Copy the slide's text"),
+		("r", "Reload the slide's image"),
+	]);
+	if !is_kiosk {
+		keybindings.push(("e", "Open the presentation file"));
+	}
+	keybindings.extend([
+		(
+			if is_kiosk {
+				"Ctrl+Escape, Ctrl+q"
+			} else {
+				"Escape, q"
+			},
+			"Quit",
+		),
+		("?, F1", "Show this help"),
+	]);
+
+	keybindings
+}
+
+/// When the presentation returns to the first slide for being idle.
+///
+/// Automatic advances count as activity along with input, so that a
+/// presentation that advances by itself isn't reset after every advance once
+/// the idle period has passed.
+fn idle_reset_time(
+	last_input_time: Instant,
+	last_advance_time: Instant,
+	idle_reset_duration: Duration,
+) -> Option<Instant> {
+	last_input_time
+		.max(last_advance_time)
+		.checked_add(idle_reset_duration)
+}
+
 fn change_slides(
-	window: &Window,
-	presentation: &Presentation,
-	current_slide: &mut usize,
+	renderer: &mut Renderer,
+	navigation: &mut Navigation,
+	countdown: &mut Option<Countdown>,
 	forward: bool,
 ) {
-	if forward {
-		if *current_slide < presentation.slides.len() - 1 {
-			*current_slide += 1;
-			window.request_redraw();
+	// Moving forward during the countdown skips the rest of it, and there's
+	// nothing to move back to
+	if let Some(countdown) = countdown {
+		if forward {
+			countdown.skip();
+			renderer.get_window().request_redraw();
 		}
-	} else if *current_slide > 0 {
-		*current_slide -= 1;
-		window.request_redraw();
+		return;
+	}
+
+	if navigation.step(forward) {
+		renderer.get_window().request_redraw();
+	} else {
+		// Make it clear that the presentation has ended, instead of appearing to
+		// ignore the input
+		renderer.show_notification(if forward {
+			"End of the presentation"
+		} else {
+			"Start of the presentation"
+		});
+	}
+}
+
+/// Goes to the first slide containing a search query as it's typed, starting
+/// from the slide the search started from, and shows the query.
+///
+/// If no slides contain the query, the starting slide is shown instead.
+fn show_first_match(
+	renderer: &mut Renderer,
+	navigation: &mut Navigation,
+	presentation: &Presentation,
+	query: &str,
+	start_slide: usize,
+) {
+	let matching_slide = presentation
+		.find_text(query, start_slide, true)
+		.filter(|_| !query.is_empty());
+	if navigation.go_to(matching_slide.unwrap_or(start_slide)) {
+		renderer.get_window().request_redraw();
+	}
+
+	renderer.show_notification(if query.is_empty() || matching_slide.is_some() {
+		format!("Find: {query}")
+	} else {
+		format!("Find: {query} (no matches)")
+	});
+}
+
+/// Goes to the next slide containing a search query after the current one,
+/// or the previous one if `forward` is `false`.
+fn show_next_match(
+	renderer: &mut Renderer,
+	navigation: &mut Navigation,
+	presentation: &Presentation,
+	query: &str,
+	forward: bool,
+) {
+	let slide_count = presentation.slides.len().max(1);
+	let current_slide = navigation.current_slide();
+	let start_slide = if forward {
+		(current_slide + 1) % slide_count
+	} else {
+		(current_slide + slide_count - 1) % slide_count
+	};
+
+	let matching_slide = presentation
+		.find_text(query, start_slide, forward)
+		.filter(|&matching_slide| matching_slide != current_slide);
+	if let Some(matching_slide) = matching_slide {
+		navigation.go_to(matching_slide);
+		renderer.get_window().request_redraw();
+	} else {
+		renderer.show_notification(format!("There are no other slides containing \"{query}\"."));
 	}
 }
 
-fn toggle_fullscreen(window: &Window, is_fullscreen: &mut bool) {
+fn toggle_fullscreen(
+	window: &Window,
+	fullscreen: &Fullscreen,
+	is_fullscreen: &mut bool,
+	sleep_inhibitor: &mut SleepInhibitor,
+) {
 	if *is_fullscreen {
 		// Disable fullscreen
 		window.set_fullscreen(None);
 	} else {
 		// Enable fullscreen
-		window.set_fullscreen(Some(FULLSCREEN_VALUE));
+		window.set_fullscreen(Some(fullscreen.clone()));
 	}
 
 	*is_fullscreen = !*is_fullscreen;
+	// Sleep is only inhibited while fullscreen, since the presentation might not
+	// be what's being looked at otherwise
+	sleep_inhibitor.set_inhibited(*is_fullscreen);
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::time::{Duration, Instant};
+
+	use super::idle_reset_time;
+
+	#[test]
+	fn idle_reset_after_advances() {
+		let start_time = Instant::now();
+		let idle_reset_duration = Duration::from_secs(60);
+		let actual_result = [
+			idle_reset_time(start_time, start_time, idle_reset_duration),
+			// An automatic advance after the idle period restarts it, instead of being
+			// immediately reset
+			idle_reset_time(
+				start_time,
+				start_time + Duration::from_secs(90),
+				idle_reset_duration,
+			),
+			idle_reset_time(
+				start_time + Duration::from_secs(30),
+				start_time + Duration::from_secs(10),
+				idle_reset_duration,
+			),
+		];
+
+		let expected_result = [
+			Some(start_time + Duration::from_secs(60)),
+			Some(start_time + Duration::from_secs(150)),
+			Some(start_time + Duration::from_secs(90)),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
 }
@@ -0,0 +1,481 @@
+//! The navigation state for moving through the slides of a presentation.
+
+// Uses
+use crate::shuffle::shuffle;
+
+// Constants
+/// The most slides that are remembered for going back through the history,
+/// with the oldest being forgotten first.
+const MAXIMUM_HISTORY_LENGTH: usize = 64;
+
+/// The current position within a presentation.
+///
+/// This encapsulates the bounds of the presentation, so that the position is
+/// always a valid slide index.
+///
+/// Slides can be shown in several steps, such as to reveal their lines one at
+/// a time, which are moved through before moving to another slide.
+///
+/// The slides are moved through in order, unless they've been shuffled, in
+/// which case moving forwards and backwards follows the shuffled order.
+///
+/// Backup slides are left out when moving forwards and backwards, and can
+/// only be reached by going to them directly or stepping through the backup
+/// slides on their own.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Navigation {
+	/// The index within the order of the current slide.
+	position:      usize,
+	current_step:  usize,
+	/// The indices of the slides, in the order they're moved through.
+	order:         Vec<usize>,
+	/// The number of steps each slide is shown in, which is at least one.
+	step_counts:   Vec<usize>,
+	/// Whether moving past either end of the presentation wraps around to the
+	/// other end.
+	wrap_around:   bool,
+	/// The slides that were previously shown, with the most recent last.
+	history:       Vec<usize>,
+	/// Whether each slide is a backup slide.
+	backup_slides: Vec<bool>,
+}
+
+impl Navigation {
+	pub fn new(slide_count: usize, wrap_around: bool) -> Self {
+		Self::with_steps(vec![1; slide_count], wrap_around)
+	}
+
+	/// Creates a navigation where each slide is shown in the provided number
+	/// of steps.
+	pub fn with_steps(step_counts: Vec<usize>, wrap_around: bool) -> Self {
+		Self {
+			position: 0,
+			current_step: 0,
+			order: (0..step_counts.len()).collect(),
+			step_counts,
+			wrap_around,
+			history: Vec::new(),
+			backup_slides: Vec::new(),
+		}
+	}
+
+	/// Marks which slides are backup slides, and moves to the first slide that
+	/// isn't one.
+	#[must_use]
+	pub fn with_backup_slides(mut self, backup_slides: Vec<bool>) -> Self {
+		self.backup_slides = backup_slides;
+		self.position = self.first_main_position();
+
+		self
+	}
+
+	/// Shuffles the order the slides are moved through, and moves to the first
+	/// slide in the new order.
+	///
+	/// The same seed always results in the same order.
+	pub fn shuffle(&mut self, seed: u64) {
+		shuffle(&mut self.order, seed);
+		self.position = self.first_main_position();
+		self.current_step = 0;
+		self.history.clear();
+	}
+
+	pub fn current_slide(&self) -> usize {
+		self.order.get(self.position).copied().unwrap_or(0)
+	}
+
+	/// The position of the current slide in the order, not counting backup
+	/// slides, along with the number of slides that aren't backup slides.
+	///
+	/// Returns `None` if the current slide is a backup slide.
+	pub fn main_position(&self) -> Option<(usize, usize)> {
+		let current_slide = self
+			.order
+			.get(self.position)
+			.filter(|&&slide| !self.is_backup(slide))?;
+
+		let mut main_slides = self.order.iter().filter(|&&slide| !self.is_backup(slide));
+		let position = main_slides
+			.by_ref()
+			.position(|slide| slide == current_slide)
+			.expect("the current slide is in the order");
+
+		Some((position, position + 1 + main_slides.count()))
+	}
+
+	/// The step of the current slide, starting from `0`.
+	pub fn current_step(&self) -> usize {
+		self.current_step
+	}
+
+	/// Whether the current slide is the first in the order, not counting
+	/// backup slides.
+	pub fn is_at_start(&self) -> bool {
+		self.position == self.first_main_position()
+	}
+
+	/// Moves to the next step or slide, or the previous one if `forward` is
+	/// `false`.
+	///
+	/// Moving back to a previous slide shows it in its final step.
+	///
+	/// Returns whether the current slide or step changed.
+	pub fn step(&mut self, forward: bool) -> bool {
+		if self.order.is_empty() {
+			return false;
+		}
+
+		if forward && self.current_step + 1 < self.step_counts[self.current_slide()] {
+			self.current_step += 1;
+			return true;
+		}
+		if !forward && self.current_step > 0 {
+			self.current_step -= 1;
+			return true;
+		}
+
+		let Some(new_position) = self.next_main_position(forward) else {
+			return false;
+		};
+
+		let new_slide = self.order[new_position];
+		let changed = self.go_to(new_slide);
+		if changed && !forward {
+			self.current_step = self.step_counts[new_slide].max(1) - 1;
+		}
+
+		changed
+	}
+
+	/// Moves to the first step of a specific slide, if it exists.
+	///
+	/// Returns whether the current slide changed.
+	pub fn go_to(&mut self, slide: usize) -> bool {
+		if slide >= self.order.len() || slide == self.current_slide() {
+			return false;
+		}
+
+		if self.history.len() == MAXIMUM_HISTORY_LENGTH {
+			self.history.remove(0);
+		}
+		self.history.push(self.current_slide());
+		self.move_to(slide);
+
+		true
+	}
+
+	/// Moves to the first step of the slide at a position in the order, not
+	/// counting backup slides, such as the third slide of the presentation's
+	/// normal flow.
+	///
+	/// Returns whether the current slide changed.
+	pub fn go_to_position(&mut self, position: usize) -> bool {
+		let slide = self
+			.order
+			.iter()
+			.copied()
+			.filter(|&slide| !self.is_backup(slide))
+			.nth(position);
+
+		match slide {
+			Some(slide) => self.go_to(slide),
+			None => false,
+		}
+	}
+
+	/// Moves to the first step of the first slide in the order, not counting
+	/// backup slides.
+	///
+	/// Returns whether the current slide changed.
+	pub fn go_to_start(&mut self) -> bool {
+		match self.order.get(self.first_main_position()) {
+			Some(&first_slide) => self.go_to(first_slide),
+			None => false,
+		}
+	}
+
+	/// Moves to the first step of the next backup slide in the order, wrapping
+	/// around to the first one after the last.
+	///
+	/// Returns whether the current slide changed.
+	pub fn go_to_next_backup(&mut self) -> bool {
+		let slide_count = self.order.len();
+		let next_backup_slide = (1..=slide_count)
+			.map(|offset| self.order[(self.position + offset) % slide_count])
+			.find(|&slide| self.is_backup(slide));
+
+		match next_backup_slide {
+			Some(backup_slide) => self.go_to(backup_slide),
+			None => false,
+		}
+	}
+
+	/// Returns to the first step of the slide that was shown before the
+	/// current one, regardless of where it is in the presentation.
+	///
+	/// Returns whether the current slide changed.
+	pub fn back(&mut self) -> bool {
+		let Some(previous_slide) = self.history.pop() else {
+			return false;
+		};
+
+		self.move_to(previous_slide);
+
+		true
+	}
+
+	fn is_backup(&self, slide: usize) -> bool {
+		self.backup_slides.get(slide).copied().unwrap_or(false)
+	}
+
+	/// The position of the first slide in the order that isn't a backup slide,
+	/// or the very first if they all are.
+	fn first_main_position(&self) -> usize {
+		self.order
+			.iter()
+			.position(|&slide| !self.is_backup(slide))
+			.unwrap_or(0)
+	}
+
+	/// The position of the next slide that isn't a backup slide, or the
+	/// previous one if `forward` is `false`.
+	///
+	/// Returns `None` if there isn't one in that direction.
+	fn next_main_position(&self, forward: bool) -> Option<usize> {
+		let slide_count = self.order.len();
+
+		(1..slide_count)
+			.filter_map(|offset| match (forward, self.wrap_around) {
+				(true, true) => Some((self.position + offset) % slide_count),
+				(true, false) => {
+					Some(self.position + offset).filter(|&position| position < slide_count)
+				}
+				(false, true) => Some((self.position + slide_count - offset) % slide_count),
+				(false, false) => self.position.checked_sub(offset),
+			})
+			.find(|&position| !self.is_backup(self.order[position]))
+	}
+
+	/// Moves to the first step of a slide, which must exist.
+	fn move_to(&mut self, slide: usize) {
+		self.position = self
+			.order
+			.iter()
+			.position(|&ordered_slide| ordered_slide == slide)
+			.expect("every slide is in the order");
+		self.current_step = 0;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::Navigation;
+
+	#[test]
+	fn single_slide() {
+		let mut navigation = Navigation::new(1, false);
+
+		assert!(!navigation.step(true));
+		assert!(!navigation.step(false));
+		assert_eq!(0, navigation.current_slide());
+	}
+
+	#[test]
+	fn single_slide_wrap_around() {
+		let mut navigation = Navigation::new(1, true);
+
+		assert!(!navigation.step(true));
+		assert!(!navigation.step(false));
+		assert_eq!(0, navigation.current_slide());
+	}
+
+	#[test]
+	fn no_slides() {
+		let mut navigation = Navigation::new(0, true);
+
+		assert!(!navigation.step(true));
+		assert!(!navigation.step(false));
+		assert!(!navigation.go_to(0));
+		assert_eq!(0, navigation.current_slide());
+	}
+
+	#[test]
+	fn boundaries() {
+		let mut navigation = Navigation::new(3, false);
+
+		assert!(!navigation.step(false));
+		assert_eq!(0, navigation.current_slide());
+
+		assert!(navigation.step(true));
+		assert!(navigation.step(true));
+		assert_eq!(2, navigation.current_slide());
+
+		assert!(!navigation.step(true));
+		assert_eq!(2, navigation.current_slide());
+
+		assert!(navigation.step(false));
+		assert_eq!(1, navigation.current_slide());
+	}
+
+	#[test]
+	fn wrap_around() {
+		let mut navigation = Navigation::new(3, true);
+
+		assert!(navigation.step(false));
+		assert_eq!(2, navigation.current_slide());
+
+		assert!(navigation.step(true));
+		assert_eq!(0, navigation.current_slide());
+	}
+
+	#[test]
+	fn go_to() {
+		let mut navigation = Navigation::new(3, false);
+
+		assert!(navigation.go_to(2));
+		assert_eq!(2, navigation.current_slide());
+
+		assert!(!navigation.go_to(2));
+		assert!(!navigation.go_to(3));
+		assert_eq!(2, navigation.current_slide());
+	}
+
+	#[test]
+	fn history() {
+		let mut navigation = Navigation::new(10, false);
+
+		assert!(navigation.go_to(7));
+		assert!(navigation.step(true));
+		assert!(navigation.go_to(2));
+
+		assert!(navigation.back());
+		assert_eq!(8, navigation.current_slide());
+		assert!(navigation.back());
+		assert_eq!(7, navigation.current_slide());
+		assert!(navigation.back());
+		assert_eq!(0, navigation.current_slide());
+		assert!(!navigation.back());
+		assert_eq!(0, navigation.current_slide());
+	}
+
+	#[test]
+	fn shuffled() {
+		let mut navigation = Navigation::new(10, false);
+		navigation.shuffle(42);
+
+		let mut actual_result = vec![navigation.current_slide()];
+		while navigation.step(true) {
+			actual_result.push(navigation.current_slide());
+		}
+		actual_result.sort_unstable();
+
+		let expected_result = (0..10).collect::<Vec<_>>();
+
+		assert_eq!(expected_result, actual_result);
+		assert!(navigation.go_to_start());
+		assert!(navigation.is_at_start());
+	}
+
+	#[test]
+	fn backup_slides() {
+		let mut navigation =
+			Navigation::new(5, false).with_backup_slides(vec![true, false, true, false, true]);
+		assert_eq!(1, navigation.current_slide());
+		assert!(navigation.is_at_start());
+
+		let mut actual_result = vec![navigation.current_slide()];
+		while navigation.step(true) {
+			actual_result.push(navigation.current_slide());
+		}
+		assert_eq!(vec![1, 3], actual_result);
+
+		assert!(navigation.go_to_next_backup());
+		assert_eq!(4, navigation.current_slide());
+		assert!(navigation.go_to_next_backup());
+		assert_eq!(0, navigation.current_slide());
+		assert!(navigation.step(true));
+		assert_eq!(1, navigation.current_slide());
+
+		assert!(navigation.go_to(2));
+		assert!(navigation.step(false));
+		assert_eq!(1, navigation.current_slide());
+		assert!(!navigation.step(false));
+	}
+
+	#[test]
+	fn go_to_position() {
+		let mut navigation =
+			Navigation::new(5, false).with_backup_slides(vec![true, false, true, false, false]);
+		navigation.shuffle(42);
+		let main_slides = {
+			let mut main_slides = vec![navigation.current_slide()];
+			while navigation.step(true) {
+				main_slides.push(navigation.current_slide());
+			}
+			main_slides
+		};
+
+		let mut actual_result = Vec::new();
+		for position in [2, 0, 1] {
+			navigation.go_to_position(position);
+			actual_result.push(navigation.current_slide());
+		}
+
+		let expected_result = vec![main_slides[2], main_slides[0], main_slides[1]];
+
+		assert_eq!(expected_result, actual_result);
+		assert!(!navigation.go_to_position(3));
+		assert_eq!(main_slides[1], navigation.current_slide());
+	}
+
+	#[test]
+	fn main_position() {
+		let mut navigation =
+			Navigation::new(5, false).with_backup_slides(vec![true, false, true, false, false]);
+		navigation.shuffle(42);
+
+		let mut actual_result = vec![navigation.main_position()];
+		while navigation.step(true) {
+			actual_result.push(navigation.main_position());
+		}
+		navigation.go_to_next_backup();
+		actual_result.push(navigation.main_position());
+
+		let expected_result = vec![Some((0, 3)), Some((1, 3)), Some((2, 3)), None];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn steps() {
+		let mut navigation = Navigation::with_steps(vec![1, 3, 1], false);
+
+		assert!(navigation.step(true));
+		assert!(navigation.step(true));
+		assert_eq!(
+			(1, 1),
+			(navigation.current_slide(), navigation.current_step())
+		);
+
+		assert!(navigation.step(true));
+		assert!(navigation.step(true));
+		assert_eq!(
+			(2, 0),
+			(navigation.current_slide(), navigation.current_step())
+		);
+
+		assert!(navigation.step(false));
+		assert_eq!(
+			(1, 2),
+			(navigation.current_slide(), navigation.current_step())
+		);
+
+		assert!(navigation.go_to(0));
+		assert!(navigation.go_to(1));
+		assert_eq!(
+			(1, 0),
+			(navigation.current_slide(), navigation.current_step())
+		);
+	}
+}
@@ -0,0 +1,53 @@
+//! A short sound played when the slide changes, as an audible confirmation of
+//! navigation.
+//!
+//! The sound is played with the platform's own sounds, so that no audio
+//! libraries are needed.
+
+// Uses
+#[cfg(not(target_os = "windows"))]
+use std::{
+	process::{Command, Stdio},
+	thread,
+};
+
+#[cfg(target_os = "windows")]
+use windows_sys::Win32::UI::WindowsAndMessaging::{MessageBeep, MB_OK};
+
+/// Starts playing the navigation sound, without waiting for it to finish.
+///
+/// Returns whether the sound could be played.
+#[cfg(target_os = "windows")]
+pub fn play_navigation_sound() -> bool {
+	// SAFETY: Playing a system sound has no preconditions, and the sound type is
+	// valid
+	unsafe { MessageBeep(MB_OK) != 0 }
+}
+
+/// Starts playing the navigation sound, without waiting for it to finish.
+///
+/// Returns whether the sound could be played.
+#[cfg(not(target_os = "windows"))]
+pub fn play_navigation_sound() -> bool {
+	#[cfg(target_os = "macos")]
+	let mut command = {
+		let mut command = Command::new("afplay");
+		command.arg("/System/Library/Sounds/Tink.aiff");
+		command
+	};
+	#[cfg(not(target_os = "macos"))]
+	let mut command = {
+		let mut command = Command::new("canberra-gtk-play");
+		command.arg("--id=message");
+		command
+	};
+
+	command
+		.stdin(Stdio::null())
+		.stdout(Stdio::null())
+		.stderr(Stdio::null())
+		.spawn()
+		// Reap the process once it exits, without blocking the event loop
+		.map(|mut child| thread::spawn(move || child.wait()))
+		.is_ok()
+}
@@ -3,394 +3,4221 @@
 //! [`sent`]: https://tools.suckless.org/sent/
 
 // Uses
-use std::{fs::read_to_string, path::Path};
+use std::{
+	error::Error,
+	ffi::OsStr,
+	fmt,
+	fs::{read, read_dir},
+	io::{self, ErrorKind, Read},
+	mem::take,
+	path::{Path, PathBuf},
+	slice,
+	str::FromStr,
+	time::Duration,
+};
 
-use crate::LinearRgbaColour;
+use encoding_rs::Encoding;
+use image::ImageFormat;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{
+	date::{civil_date, parse_minutes_and_seconds, SECONDS_PER_DAY},
+	LinearRgbaColour,
+};
 
 // Constants
+/// The character put on the end of text that has been truncated.
+pub const ELLIPSIS: char = '\u{2026}';
 const COMMENT_MARKER: char = '#';
 const IMAGE_SLIDE_MARKER: char = '@';
 const ESCAPE_MARKER: char = '\\';
 const OPTION_MARKER: &str = "#.";
 const OPTION_SEPARATOR: char = ':';
+/// The file extensions that cause an image slide to be treated as a video
+/// slide instead.
+const VIDEO_FILE_EXTENSIONS: &[&str] = &["avi", "m4v", "mkv", "mov", "mp4", "webm"];
+/// The characters that make the file name of an image slide a pattern that
+/// expands to every matching file.
+const WILDCARD_CHARS: &[char] = &['*', '?'];
+
+/// Makes the paragraph it's in an empty slide, as an alternative to a lone
+/// escape marker.
+const EMPTY_SLIDE_DIRECTIVE: &str = "empty";
+/// Names the slide, so that it can be jumped to.
+///
+/// In a paragraph of its own, it names the next slide instead.
+const ANCHOR_OPTION_NAME: &str = "anchor";
+
+/// Starts a run of text in a different colour, which is followed by a hex
+/// code and [`COLOUR_SPAN_TAG_END`].
+const COLOUR_SPAN_START: &str = "[color=";
+const COLOUR_SPAN_TAG_END: char = ']';
+const COLOUR_SPAN_END: &str = "[/color]";
 
+const TITLE_OPTION_NAME: &str = "title";
+/// Splices the slides of another presentation file in at its position.
+const INCLUDE_OPTION_NAME: &str = "include";
 const FONT_OPTION_NAME: &str = "font";
 const FOREGROUND_COLOUR_OPTION_NAME: &str = "fg";
+const FOREGROUND_MODE_OPTION_NAME: &str = "fg-mode";
 const BACKGROUND_COLOUR_OPTION_NAME: &str = "bg";
+const STAGE_COLOUR_OPTION_NAME: &str = "stage-colour";
+const BLANK_COLOUR_OPTION_NAME: &str = "blank-colour";
+const DARK_THEME_OPTION_NAME: &str = "theme-dark";
+const LIGHT_THEME_OPTION_NAME: &str = "theme-light";
+const FIT_MODE_OPTION_NAME: &str = "fit";
+const FIT_OVERFLOW_OPTION_NAME: &str = "fit-overflow";
+const ANIMATIONS_OPTION_NAME: &str = "animations";
+const MIRROR_OPTION_NAME: &str = "mirror";
+const OPACITY_OPTION_NAME: &str = "opacity";
+const KIOSK_OPTION_NAME: &str = "kiosk";
+const BULLETS_OPTION_NAME: &str = "bullets";
+const IDLE_RESET_OPTION_NAME: &str = "idle-reset";
+const TEXT_OUTLINE_COLOUR_OPTION_NAME: &str = "text-outline";
+const TEXT_OUTLINE_WIDTH_OPTION_NAME: &str = "text-outline-width";
+const HORIZONTAL_MARGIN_OPTION_NAME: &str = "margin-x";
+const VERTICAL_MARGIN_OPTION_NAME: &str = "margin-y";
+const WATERMARK_OPTION_NAME: &str = "watermark";
+const WATERMARK_POSITION_OPTION_NAME: &str = "watermark-position";
+const WATERMARK_OPACITY_OPTION_NAME: &str = "watermark-opacity";
+const MAXIMUM_IMAGE_SIZE_OPTION_NAME: &str = "max-image-size";
+const COUNTDOWN_OPTION_NAME: &str = "countdown";
+const TEXT_RENDERING_OPTION_NAME: &str = "text-rendering";
+const SHUFFLE_OPTION_NAME: &str = "shuffle";
+const TOUCH_SPLIT_OPTION_NAME: &str = "touch-split";
+const NAVIGATION_SOUND_OPTION_NAME: &str = "nav-sound";
+const SLIDE_NUMBERS_OPTION_NAME: &str = "slidenumbers";
+const PROGRESS_BAR_OPTION_NAME: &str = "progressbar";
+const PLACEHOLDERS_OPTION_NAME: &str = "placeholders";
+const LOOP_OPTION_NAME: &str = "loop";
+/// Replaced with the date the presentation was loaded on, as `YYYY-MM-DD`.
+const DATE_PLACEHOLDER: &str = "{date}";
+/// Replaced with the time the presentation was loaded at, as `HH:MM`.
+const TIME_PLACEHOLDER: &str = "{time}";
+/// Replaced with the number of slides.
+const SLIDE_COUNT_PLACEHOLDER: &str = "{slides}";
+const VERTICAL_ALIGNMENT_OPTION_NAME: &str = "valign";
+const HORIZONTAL_ALIGNMENT_OPTION_NAME: &str = "align";
+const FONT_SIZE_OPTION_NAME: &str = "font-size";
+const SLIDE_TYPE_OPTION_NAME: &str = "slide-type";
+const SCROLL_DURATION_OPTION_NAME: &str = "scroll-duration";
+const ADVANCE_OPTION_NAME: &str = "advance";
+const NEAREST_THRESHOLD_OPTION_NAME: &str = "nearest-threshold";
+const SUBTITLE_SCALE_OPTION_NAME: &str = "subtitle-scale";
+const SCALE_OPTION_NAME: &str = "scale";
+/// The smallest multiplier a slide's text can be scaled by, so that it can't
+/// vanish entirely.
+const MINIMUM_SCALE: f32 = 0.05;
+const ONLY_OPTION_NAME: &str = "only";
+const REVEAL_OPTION_NAME: &str = "reveal";
+const MAXIMUM_LINES_OPTION_NAME: &str = "max-lines";
+const SLIDE_MARGIN_OPTION_NAME: &str = "slide-margin";
+const SLIDE_FOREGROUND_COLOUR_OPTION_NAME: &str = "slide-fg";
+const BACKGROUND_IMAGE_OPTION_NAME: &str = "bgimage";
+const BACKUP_OPTION_NAME: &str = "backup";
+const CAPTIONS_OPTION_NAME: &str = "captions";
+/// Normalises the text of every slide in the file to NFC, so that decomposed
+/// characters (eg. `e` followed by a combining acute accent) are treated as
+/// single characters.
+const NORMALISE_OPTION_NAME: &str = "normalize";
+/// The options whose values are colours, which are checked by
+/// [`Presentation::check`].
+const COLOUR_OPTION_NAMES: &[&str] = &[
+	FOREGROUND_COLOUR_OPTION_NAME,
+	BACKGROUND_COLOUR_OPTION_NAME,
+	STAGE_COLOUR_OPTION_NAME,
+	BLANK_COLOUR_OPTION_NAME,
+	TEXT_OUTLINE_COLOUR_OPTION_NAME,
+	SLIDE_FOREGROUND_COLOUR_OPTION_NAME,
+];
+/// The options that are either `true` or `false`, which are checked by
+/// [`Presentation::check`].
+const BOOL_OPTION_NAMES: &[&str] = &[
+	ANIMATIONS_OPTION_NAME,
+	SHUFFLE_OPTION_NAME,
+	NAVIGATION_SOUND_OPTION_NAME,
+	SLIDE_NUMBERS_OPTION_NAME,
+	PROGRESS_BAR_OPTION_NAME,
+	LOOP_OPTION_NAME,
+	PLACEHOLDERS_OPTION_NAME,
+	NORMALISE_OPTION_NAME,
+	KIOSK_OPTION_NAME,
+	BULLETS_OPTION_NAME,
+	REVEAL_OPTION_NAME,
+	BACKUP_OPTION_NAME,
+	CAPTIONS_OPTION_NAME,
+];
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Presentation {
-	pub font_list:         Vec<String>,
-	pub foreground_colour: Option<LinearRgbaColour>,
-	pub background_colour: Option<LinearRgbaColour>,
-	pub slides:            Vec<Slide>,
+	/// The title of the presentation, which is otherwise taken from the first
+	/// text slide.
+	pub title:                 Option<String>,
+	pub font_list:             Vec<String>,
+	pub foreground_colour:     Option<LinearRgbaColour>,
+	/// Every foreground colour that was set, in order, which text slides can
+	/// cycle through.
+	pub foreground_palette:    Vec<LinearRgbaColour>,
+	pub foreground_mode:       Option<ForegroundMode>,
+	pub background_colour:     Option<LinearRgbaColour>,
+	/// The colour of the screen outside of the usable space, which defaults to
+	/// the background colour.
+	pub stage_colour:          Option<LinearRgbaColour>,
+	/// The colour the whole screen is filled with while it's blanked, which
+	/// defaults to the background colour.
+	pub blank_colour:          Option<LinearRgbaColour>,
+	pub dark_theme:            Option<Theme>,
+	pub light_theme:           Option<Theme>,
+	pub fit_mode:              Option<FitMode>,
+	pub fit_overflow:          Option<FitOverflow>,
+	pub animations:            Option<bool>,
+	pub mirror:                Option<Mirror>,
+	/// The opacity of the text and of everything drawn over the slides, from
+	/// `0.0` to `1.0`.
+	pub opacity:               Option<f32>,
+	/// Whether the presentation is for an unattended display, which can't be
+	/// closed by accident.
+	pub kiosk:                 Option<bool>,
+	/// Whether wrapped bullet points are indented to align with the text after
+	/// the bullet.
+	pub bullets:               Option<bool>,
+	/// How long without any input before returning to the first slide.
+	pub idle_reset:            Option<Duration>,
+	/// The colour of the outline drawn around text, if any.
+	pub text_outline_colour:   Option<LinearRgbaColour>,
+	pub text_outline_width:    Option<f32>,
+	/// The space left empty on the left and right of the screen, as a
+	/// fraction of its width.
+	pub horizontal_margin:     Option<f32>,
+	/// The space left empty at the top and bottom of the screen, as a fraction
+	/// of its height.
+	pub vertical_margin:       Option<f32>,
+	/// The path of an image drawn on top of every slide, such as a logo.
+	pub watermark:             Option<String>,
+	/// Where the watermark is placed on the screen.
+	pub watermark_position:    Option<Anchor>,
+	/// The opacity of the watermark, from `0.0` to `1.0`.
+	pub watermark_opacity:     Option<f32>,
+	/// The largest either dimension of an image can be, in pixels, with any
+	/// larger images being downscaled when they're loaded.
+	pub maximum_image_size:    Option<u32>,
+	/// How long to count down for before the first slide is shown.
+	pub countdown:             Option<Duration>,
+	pub text_rendering:        Option<TextRendering>,
+	/// Whether the slides are shown in a random order.
+	pub shuffle:               Option<bool>,
+	/// Where the screen is split between going back and going forward when
+	/// it's tapped, as a fraction of its width from the left.
+	pub touch_split:           Option<f32>,
+	/// Whether a short sound is played whenever the slide changes.
+	pub navigation_sound:      Option<bool>,
+	/// Whether the number of the current slide and the number of slides are
+	/// shown in a corner of the screen.
+	pub show_slide_numbers:    Option<bool>,
+	/// Whether a bar along the bottom of the screen shows how far through the
+	/// presentation the current slide is.
+	pub show_progress_bar:     Option<bool>,
+	/// Whether placeholders such as `{date}` in text slides are replaced with
+	/// their values.
+	pub placeholders:          Option<bool>,
+	/// Whether moving past the last slide wraps around to the first, and
+	/// moving back from the first slide wraps around to the last.
+	pub looping:               Option<bool>,
+	/// The slide options that apply to every slide that doesn't set them
+	/// itself.
+	pub default_slide_options: SlideOptions,
+	pub slides:                Vec<Slide>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slide {
+	pub content: SlideContent,
+	pub options: SlideOptions,
+	/// The name of the slide, for jumping to it.
+	pub anchor:  Option<String>,
+	/// The text following the image of an image slide, which is only shown if
+	/// the slide's captions are turned on.
+	pub caption: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum Slide {
+pub enum SlideContent {
 	Text(String),
 	Image(String),
+	/// Several images, arranged in a grid.
+	Images(Vec<String>),
+	Video(String),
 	Empty,
 }
 
+/// A run of text within a text slide.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSpan<'a> {
+	pub text:   &'a str,
+	/// The colour of the run, which overrides the foreground colour.
+	pub colour: Option<LinearRgbaColour>,
+}
+
+/// Options that can be set for individual slides.
+///
+/// Slide options that are set within the paragraph of a slide apply only to
+/// that slide, while those set in a paragraph of their own apply to every
+/// slide by default.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SlideOptions {
+	pub vertical_alignment:   Option<VerticalAlignment>,
+	/// How each line of text is aligned, instead of the text being centered
+	/// as a block with its lines aligned to the left.
+	pub horizontal_alignment: Option<HorizontalAlignment>,
+	/// An absolute font size in points, which disables fitting the text to the
+	/// usable space.
+	pub font_size:            Option<f32>,
+	/// Where image and video slides are placed within the usable space.
+	pub image_anchor:         Option<Anchor>,
+	pub slide_type:           Option<SlideType>,
+	/// How long a scrolling slide takes to scroll past.
+	pub scroll_duration:      Option<Duration>,
+	/// How long the slide is shown before the presentation advances by
+	/// itself, counted from when it was shown or from the last input.
+	pub advance:              Option<Duration>,
+	/// The scaling factor at or above which images are sampled with
+	/// nearest-neighbour filtering instead of being smoothed.
+	pub nearest_threshold:    Option<f32>,
+	/// The size of the lines after the first on title slides, relative to the
+	/// first.
+	pub subtitle_scale:       Option<f32>,
+	/// A multiplier for the size of the text after it has been fitted to the
+	/// usable space.
+	pub scale:                Option<f32>,
+	/// The only output the slide is included in.
+	pub only:                 Option<Output>,
+	/// Whether the lines of a text slide are revealed one at a time as the
+	/// presentation advances.
+	pub reveal:               Option<bool>,
+	/// The most lines of text shown, with any after them cut off.
+	pub maximum_lines:        Option<usize>,
+	/// The colour of the text, which overrides the presentation's foreground
+	/// colour.
+	///
+	/// Slides that don't set it are given one when cycling through the
+	/// foreground palette.
+	pub foreground_colour:    Option<LinearRgbaColour>,
+	/// The horizontal and vertical margins, which override the presentation's
+	/// margins.
+	pub margins:              Option<(f32, f32)>,
+	/// An image scaled to cover the whole screen behind the slide.
+	pub background_image:     Option<String>,
+	/// Whether the slide is only shown if it's asked for, such as an appendix,
+	/// instead of as part of the presentation's normal flow.
+	pub backup:               Option<bool>,
+	/// Whether the text following the image of an image slide is shown as a
+	/// caption below it, instead of being treated as a comment.
+	pub captions:             Option<bool>,
+}
+
+/// How the foreground colour is chosen for each slide.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ForegroundMode {
+	/// Every slide uses the first foreground colour.
+	#[default]
+	Single,
+	/// Each text slide uses the next colour in the foreground palette,
+	/// wrapping around at the end.
+	Cycle,
+}
+
+/// The ways a presentation can be output, which slides can be limited to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Output {
+	/// The view only the presenter sees.
+	Presenter,
+	/// The presentation as shown to the audience.
+	Audience,
+	/// The presentation as exported, such as for a handout.
+	Export,
+}
+
+/// How the content of a slide is presented.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SlideType {
+	/// The content is shown in place.
+	#[default]
+	Static,
+	/// The text scrolls upwards continuously, like film credits, and the
+	/// presentation advances once it has scrolled past.
+	Scroll,
+	/// The first line is a large title, with the remaining lines shown smaller
+	/// beneath it, all centered.
+	Title,
+}
+
+/// A position within the usable space that content can be aligned to.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Anchor {
+	TopLeft,
+	Top,
+	TopRight,
+	Left,
+	#[default]
+	Center,
+	Right,
+	BottomLeft,
+	Bottom,
+	BottomRight,
+}
+
+/// The vertical position of text within the usable space.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum VerticalAlignment {
+	Top,
+	#[default]
+	Center,
+	Bottom,
+}
+
+/// How the lines of text are aligned horizontally within the usable space.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HorizontalAlignment {
+	Left,
+	Center,
+	Right,
+}
+
+/// A named pair of colours that can be switched between while presenting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+	pub foreground_colour: LinearRgbaColour,
+	pub background_colour: LinearRgbaColour,
+}
+
+/// The direction to flip the output in, such as for rear-projection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mirror {
+	Horizontal,
+	Vertical,
+}
+
+/// The built-in colour schemes that can be used instead of picking colours.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ThemePreset {
+	SolarizedDark,
+	SolarizedLight,
+	Dracula,
+	Mono,
+}
+
+/// The dimensions of the usable space that text is scaled to fit.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FitMode {
+	/// Fill the usable width, even if the text overflows vertically.
+	Width,
+	/// Fill the usable height, even if the text overflows horizontally.
+	Height,
+	/// Fit entirely within the usable space.
+	#[default]
+	Both,
+}
+
+/// How text that overflows the usable space is positioned, when it's only
+/// fit to one dimension.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FitOverflow {
+	/// Keep the text centered, letting the overflow be clipped by the edges of
+	/// the screen.
+	#[default]
+	Clip,
+	/// Align the text to the start (top or left) of the usable space, so that
+	/// the beginning of it stays visible.
+	Start,
+}
+
+/// How the glyphs of text are rasterised and drawn.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TextRendering {
+	/// Glyphs are reused at similar sizes and positions, and smoothed when
+	/// drawn.
+	#[default]
+	Smooth,
+	/// Glyphs are rasterised for their exact size and sub-pixel position,
+	/// which is more accurate but slower.
+	Precise,
+	/// Glyphs aren't smoothed when drawn, which looks crisper on some
+	/// displays.
+	Sharp,
+}
+
+/// An error encountered while loading a presentation.
+///
+/// Parsing the contents of a presentation never fails, since any invalid
+/// options are ignored, so the errors from loading come from the files
+/// themselves. The invalid options can be found with [`Presentation::check`]
+/// instead.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseError {
+	/// A directory was provided instead of a presentation file.
+	Directory { path: PathBuf },
+	/// A presentation file couldn't be read, or isn't text.
+	UnreadableFile { path: PathBuf, error: io::Error },
+	/// A manifest file couldn't be read, or isn't text.
+	UnreadableManifest { path: PathBuf, error: io::Error },
+	/// A bundle couldn't be read, or isn't a zip archive.
+	UnreadableBundle { path: PathBuf, error: io::Error },
+	/// A directory that an image slide refers to couldn't be read.
+	UnreadableImageDirectory { path: PathBuf, error: io::Error },
+	/// An image slide that refers to multiple files didn't match any images.
+	NoImagesFound { path: PathBuf },
+	/// A presentation file includes itself, directly or through other files.
+	IncludeCycle { path: PathBuf },
+	/// A presentation file in a bundle tries to include another file.
+	IncludeInBundle { path: PathBuf },
+	/// An option that takes a colour was given something else, on a line
+	/// counting from 1.
+	InvalidColour { line: usize, value: String },
+	/// An option that's either `true` or `false` was given something else, on
+	/// a line counting from 1.
+	InvalidBool { line: usize, value: String },
+	/// A presentation couldn't be read from somewhere other than a file, or
+	/// isn't text.
+	Io(io::Error),
+	/// An error from a file included by a presentation file.
+	InInclude {
+		error:          Box<ParseError>,
+		including_path: PathBuf,
+	},
+	/// An error from an entry in a manifest.
+	InManifest {
+		error:         Box<ParseError>,
+		line_number:   usize,
+		manifest_path: PathBuf,
+	},
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Directory { path } => write!(
+				f,
+				"expected a presentation file, but got a directory\n\"{}\"!",
+				path.to_string_lossy()
+			),
+			Self::UnreadableFile { path, .. } => write!(
+				f,
+				"unable to read the presentation file\n\"{}\"!",
+				path.to_string_lossy()
+			),
+			Self::UnreadableManifest { path, .. } => write!(
+				f,
+				"unable to read the manifest file\n\"{}\"!",
+				path.to_string_lossy()
+			),
+			Self::UnreadableBundle { path, .. } => write!(
+				f,
+				"unable to read the bundle\n\"{}\"!",
+				path.to_string_lossy()
+			),
+			Self::UnreadableImageDirectory { path, .. } => write!(
+				f,
+				"unable to read the image directory\n\"{}\"!",
+				path.to_string_lossy()
+			),
+			Self::NoImagesFound { path } => write!(
+				f,
+				"no images were found for\n\"{}\"!",
+				path.to_string_lossy()
+			),
+			Self::IncludeCycle { path } => write!(
+				f,
+				"the presentation file includes itself\n\"{}\"!",
+				path.to_string_lossy()
+			),
+			Self::IncludeInBundle { path } => write!(
+				f,
+				"presentation files in bundles can't include other files\n\"{}\"!",
+				path.to_string_lossy()
+			),
+			Self::InvalidColour { line, value } => {
+				write!(f, "invalid colour \"{value}\" on line {line}!")
+			}
+			Self::InvalidBool { line, value } => write!(
+				f,
+				"invalid value \"{value}\" on line {line} - expected true or false!"
+			),
+			Self::Io(_) => write!(f, "unable to read the presentation!"),
+			Self::InInclude {
+				error,
+				including_path,
+			} => write!(
+				f,
+				"{error}\n(included by\n\"{}\")",
+				including_path.to_string_lossy()
+			),
+			Self::InManifest {
+				error,
+				line_number,
+				manifest_path,
+			} => write!(
+				f,
+				"{error}\n(from line {line_number} of the manifest\n\"{}\")",
+				manifest_path.to_string_lossy()
+			),
+		}
+	}
+}
+
+impl Error for ParseError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InManifest { error, .. } | Self::InInclude { error, .. } => Some(error.as_ref()),
+			Self::UnreadableFile { error, .. }
+			| Self::UnreadableManifest { error, .. }
+			| Self::UnreadableBundle { error, .. }
+			| Self::UnreadableImageDirectory { error, .. }
+			| Self::Io(error) => Some(error),
+			Self::Directory { .. }
+			| Self::NoImagesFound { .. }
+			| Self::IncludeCycle { .. }
+			| Self::IncludeInBundle { .. }
+			| Self::InvalidColour { .. }
+			| Self::InvalidBool { .. } => None,
+		}
+	}
+}
+
 impl Presentation {
+	/// Loads a presentation from its contents.
+	///
+	/// Any files it includes are ignored, since they can only be found relative
+	/// to the presentation file. Use [`load_from_path`] to splice them in.
+	///
+	/// [`load_from_path`]: Self::load_from_path
+	#[must_use]
 	pub fn load(contents: &str) -> Self {
+		let (mut presentation, _) = Self::parse(contents);
+
+		// Ensure the presentation always has at least one slide, even if it only
+		// included other files
+		if presentation.slides.is_empty() {
+			presentation.slides.push(SlideContent::Empty.into());
+		}
+
+		presentation
+	}
+
+	/// Loads a presentation from a reader, such as standard input.
+	///
+	/// As with [`load`], any files it includes are ignored.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the reader fails, or what it reads isn't text.
+	///
+	/// [`load`]: Self::load
+	pub fn load_from_reader<R>(mut reader: R) -> Result<Self, ParseError>
+	where
+		R: Read,
+	{
+		let mut contents = Vec::new();
+		reader.read_to_end(&mut contents).map_err(ParseError::Io)?;
+		let contents = decode_text(contents).map_err(ParseError::Io)?;
+
+		Ok(Self::load(contents.as_str()))
+	}
+
+	/// Checks the contents of a presentation file for colour and `true` or
+	/// `false` options with values that aren't valid, which [`load`] would
+	/// otherwise ignore.
+	///
+	/// Returns an error for each of them, in the order they appear.
+	///
+	/// [`load`]: Self::load
+	#[must_use]
+	pub fn check(contents: &str) -> Vec<ParseError> {
+		contents
+			.lines()
+			.enumerate()
+			.filter_map(|(line_index, line)| {
+				let option = line.trim_end().strip_prefix(OPTION_MARKER)?;
+				let (option_name, option_value) = option.split_once(OPTION_SEPARATOR)?;
+				let line = line_index + 1;
+				let value = option_value.trim().to_owned();
+
+				if COLOUR_OPTION_NAMES.contains(&option_name)
+					&& parse_colour(option_value).is_none()
+				{
+					Some(ParseError::InvalidColour { line, value })
+				} else if BOOL_OPTION_NAMES.contains(&option_name)
+					&& parse_bool(option_value).is_none()
+				{
+					Some(ParseError::InvalidBool { line, value })
+				} else {
+					None
+				}
+			})
+			.collect()
+	}
+
+	/// Parses the contents of a presentation file.
+	///
+	/// Returns the presentation along with the paths of the files it includes,
+	/// each with the index of the slide they're spliced in before. If there are
+	/// any, the presentation may have no slides of its own.
+	#[must_use]
+	pub(crate) fn parse(contents: &str) -> (Self, Vec<(usize, String)>) {
+		let mut title = None;
 		let mut font_list = Vec::new();
 		let mut foreground_colour = None;
+		let mut foreground_palette = Vec::new();
+		let mut foreground_mode = None;
 		let mut background_colour = None;
+		let mut stage_colour = None;
+		let mut blank_colour = None;
+		let mut dark_theme = None;
+		let mut light_theme = None;
+		let mut fit_mode = None;
+		let mut fit_overflow = None;
+		let mut animations = None;
+		let mut mirror = None;
+		let mut opacity = None;
+		let mut kiosk = None;
+		let mut bullets = None;
+		let mut idle_reset = None;
+		let mut text_outline_colour = None;
+		let mut text_outline_width = None;
+		let mut horizontal_margin = None;
+		let mut vertical_margin = None;
+		let mut watermark = None;
+		let mut watermark_position = None;
+		let mut watermark_opacity = None;
+		let mut maximum_image_size = None;
+		let mut countdown = None;
+		let mut text_rendering = None;
+		let mut shuffle = None;
+		let mut touch_split = None;
+		let mut navigation_sound = None;
+		let mut show_slide_numbers = None;
+		let mut show_progress_bar = None;
+		let mut placeholders = None;
+		let mut looping = None;
+		let mut includes = Vec::new();
+		let mut normalise = None;
+		let mut default_slide_options = SlideOptions::default();
 		let mut slides = Vec::new();
 
-		let mut current_paragraph = String::new();
-		let mut skip_remainder_of_paragraph = false;
+		let mut paragraph = Paragraph::default();
 		for line in contents.lines() {
 			let mut line_trimmed = line.trim_end();
 
 			// If the line is empty, the paragraph is complete
 			if line_trimmed.is_empty() {
-				if !current_paragraph.is_empty() {
-					slides.push(Slide::Text(current_paragraph));
-					current_paragraph = String::new();
-				}
-
-				skip_remainder_of_paragraph = false;
+				paragraph.finish(&mut slides, &mut default_slide_options);
 
 				continue;
 			}
 
 			// Parse presentation options
-			if line_trimmed.starts_with(OPTION_MARKER) {
-				if let Some((option_name, option_value)) = line_trimmed
-					.strip_prefix(OPTION_MARKER)
-					.expect("the string starts with the prefix")
-					.split_once(OPTION_SEPARATOR)
-				{
+			if let Some(option) = line_trimmed.strip_prefix(OPTION_MARKER) {
+				if let Some((option_name, option_value)) = option.split_once(OPTION_SEPARATOR) {
+					let slide_options = &mut paragraph.slide_options;
 					match option_name {
+						ANCHOR_OPTION_NAME => {
+							if paragraph.anchor.is_none() {
+								let anchor = option_value.trim();
+								paragraph.anchor = (!anchor.is_empty()).then(|| anchor.to_owned());
+							}
+						}
+						INCLUDE_OPTION_NAME => {
+							let include_path = option_value.trim();
+							if !include_path.is_empty() {
+								includes.push((slides.len(), include_path.to_owned()));
+							}
+						}
 						FONT_OPTION_NAME => font_list.push(option_value.to_owned()),
 						FOREGROUND_COLOUR_OPTION_NAME => {
-							if foreground_colour.is_none() {
-								foreground_colour = parse_colour_hex_code(option_value);
+							if let Some(colour) = parse_colour(option_value) {
+								foreground_colour = foreground_colour.or(Some(colour));
+								foreground_palette.push(colour);
+							}
+						}
+						FOREGROUND_MODE_OPTION_NAME => {
+							if foreground_mode.is_none() {
+								foreground_mode = option_value.trim().parse().ok();
 							}
 						}
 						BACKGROUND_COLOUR_OPTION_NAME => {
 							if background_colour.is_none() {
-								background_colour = parse_colour_hex_code(option_value);
+								background_colour = parse_colour(option_value);
+							}
+						}
+						STAGE_COLOUR_OPTION_NAME => {
+							if stage_colour.is_none() {
+								stage_colour = parse_colour(option_value);
+							}
+						}
+						BLANK_COLOUR_OPTION_NAME => {
+							if blank_colour.is_none() {
+								blank_colour = parse_colour(option_value);
+							}
+						}
+						DARK_THEME_OPTION_NAME => {
+							if dark_theme.is_none() {
+								dark_theme = parse_theme(option_value);
+							}
+						}
+						LIGHT_THEME_OPTION_NAME => {
+							if light_theme.is_none() {
+								light_theme = parse_theme(option_value);
+							}
+						}
+						FIT_MODE_OPTION_NAME => {
+							if fit_mode.is_none() {
+								fit_mode = option_value.trim().parse().ok();
+							}
+						}
+						FIT_OVERFLOW_OPTION_NAME => {
+							if fit_overflow.is_none() {
+								fit_overflow = option_value.trim().parse().ok();
+							}
+						}
+						ANIMATIONS_OPTION_NAME => {
+							if animations.is_none() {
+								animations = parse_bool(option_value);
+							}
+						}
+						TITLE_OPTION_NAME => {
+							if title.is_none() {
+								let title_value = option_value.trim();
+								title = (!title_value.is_empty()).then(|| title_value.to_owned());
+							}
+						}
+						WATERMARK_OPTION_NAME => {
+							if watermark.is_none() {
+								let watermark_path = option_value.trim();
+								watermark =
+									(!watermark_path.is_empty()).then(|| watermark_path.to_owned());
+							}
+						}
+						WATERMARK_POSITION_OPTION_NAME => {
+							if watermark_position.is_none() {
+								watermark_position = option_value.trim().parse().ok();
+							}
+						}
+						MAXIMUM_IMAGE_SIZE_OPTION_NAME => {
+							if maximum_image_size.is_none() {
+								maximum_image_size = option_value
+									.trim()
+									.parse()
+									.ok()
+									.filter(|&maximum_image_size| maximum_image_size > 0);
+							}
+						}
+						SHUFFLE_OPTION_NAME => {
+							if shuffle.is_none() {
+								shuffle = parse_bool(option_value);
+							}
+						}
+						TOUCH_SPLIT_OPTION_NAME => {
+							if touch_split.is_none() {
+								touch_split = parse_fraction(option_value);
+							}
+						}
+						NAVIGATION_SOUND_OPTION_NAME => {
+							if navigation_sound.is_none() {
+								navigation_sound = parse_bool(option_value);
+							}
+						}
+						SLIDE_NUMBERS_OPTION_NAME => {
+							if show_slide_numbers.is_none() {
+								show_slide_numbers = parse_bool(option_value);
+							}
+						}
+						PROGRESS_BAR_OPTION_NAME => {
+							if show_progress_bar.is_none() {
+								show_progress_bar = parse_bool(option_value);
+							}
+						}
+						LOOP_OPTION_NAME => {
+							if looping.is_none() {
+								looping = parse_bool(option_value);
+							}
+						}
+						PLACEHOLDERS_OPTION_NAME => {
+							if placeholders.is_none() {
+								placeholders = parse_bool(option_value);
+							}
+						}
+						TEXT_RENDERING_OPTION_NAME => {
+							if text_rendering.is_none() {
+								text_rendering = option_value.trim().parse().ok();
+							}
+						}
+						COUNTDOWN_OPTION_NAME => {
+							if countdown.is_none() {
+								countdown = parse_countdown(option_value);
+							}
+						}
+						WATERMARK_OPACITY_OPTION_NAME => {
+							if watermark_opacity.is_none() {
+								watermark_opacity = parse_fraction(option_value);
+							}
+						}
+						NORMALISE_OPTION_NAME => {
+							if normalise.is_none() {
+								normalise = parse_bool(option_value);
+							}
+						}
+						MIRROR_OPTION_NAME => {
+							if mirror.is_none() {
+								mirror = option_value.trim().parse().ok();
+							}
+						}
+						OPACITY_OPTION_NAME => {
+							if opacity.is_none() {
+								opacity = parse_fraction(option_value);
+							}
+						}
+						KIOSK_OPTION_NAME => {
+							if kiosk.is_none() {
+								kiosk = parse_bool(option_value);
+							}
+						}
+						BULLETS_OPTION_NAME => {
+							if bullets.is_none() {
+								bullets = parse_bool(option_value);
+							}
+						}
+						IDLE_RESET_OPTION_NAME => {
+							if idle_reset.is_none() {
+								idle_reset = parse_seconds(option_value);
+							}
+						}
+						TEXT_OUTLINE_COLOUR_OPTION_NAME => {
+							if text_outline_colour.is_none() {
+								text_outline_colour = parse_colour(option_value);
+							}
+						}
+						TEXT_OUTLINE_WIDTH_OPTION_NAME => {
+							if text_outline_width.is_none() {
+								text_outline_width = parse_positive_number(option_value);
+							}
+						}
+						HORIZONTAL_MARGIN_OPTION_NAME => {
+							if horizontal_margin.is_none() {
+								horizontal_margin = parse_margin(option_value);
+							}
+						}
+						VERTICAL_MARGIN_OPTION_NAME => {
+							if vertical_margin.is_none() {
+								vertical_margin = parse_margin(option_value);
+							}
+						}
+						VERTICAL_ALIGNMENT_OPTION_NAME => {
+							if slide_options.vertical_alignment.is_none() {
+								slide_options.vertical_alignment = option_value.trim().parse().ok();
+							}
+						}
+						HORIZONTAL_ALIGNMENT_OPTION_NAME => {
+							if slide_options.horizontal_alignment.is_none() {
+								slide_options.horizontal_alignment =
+									option_value.trim().parse().ok();
+							}
+						}
+						FONT_SIZE_OPTION_NAME => {
+							if slide_options.font_size.is_none() {
+								slide_options.font_size = parse_positive_number(option_value);
+							}
+						}
+						SLIDE_TYPE_OPTION_NAME => {
+							if slide_options.slide_type.is_none() {
+								slide_options.slide_type = option_value.trim().parse().ok();
+							}
+						}
+						ADVANCE_OPTION_NAME => {
+							if slide_options.advance.is_none() {
+								slide_options.advance = parse_advance(option_value);
+							}
+						}
+						SCROLL_DURATION_OPTION_NAME => {
+							if slide_options.scroll_duration.is_none() {
+								slide_options.scroll_duration = parse_seconds(option_value);
+							}
+						}
+						SUBTITLE_SCALE_OPTION_NAME => {
+							if slide_options.subtitle_scale.is_none() {
+								slide_options.subtitle_scale = parse_positive_number(option_value);
+							}
+						}
+						SCALE_OPTION_NAME => {
+							if slide_options.scale.is_none() {
+								slide_options.scale = option_value
+									.trim()
+									.parse::<f32>()
+									.ok()
+									.filter(|scale| scale.is_finite())
+									.map(|scale| scale.max(MINIMUM_SCALE));
+							}
+						}
+						MAXIMUM_LINES_OPTION_NAME => {
+							if slide_options.maximum_lines.is_none() {
+								slide_options.maximum_lines = option_value
+									.trim()
+									.parse()
+									.ok()
+									.filter(|&maximum_lines| maximum_lines > 0);
+							}
+						}
+						SLIDE_MARGIN_OPTION_NAME => {
+							if slide_options.margins.is_none() {
+								slide_options.margins = parse_slide_margins(option_value);
+							}
+						}
+						SLIDE_FOREGROUND_COLOUR_OPTION_NAME => {
+							if slide_options.foreground_colour.is_none() {
+								slide_options.foreground_colour = parse_colour(option_value);
+							}
+						}
+						BACKGROUND_IMAGE_OPTION_NAME => {
+							if slide_options.background_image.is_none() {
+								let background_image_path = option_value.trim();
+								slide_options.background_image = (!background_image_path
+									.is_empty())
+								.then(|| background_image_path.to_owned());
+							}
+						}
+						REVEAL_OPTION_NAME => {
+							if slide_options.reveal.is_none() {
+								slide_options.reveal = parse_bool(option_value);
+							}
+						}
+						BACKUP_OPTION_NAME => {
+							if slide_options.backup.is_none() {
+								slide_options.backup = parse_bool(option_value);
+							}
+						}
+						CAPTIONS_OPTION_NAME => {
+							if slide_options.captions.is_none() {
+								slide_options.captions = parse_bool(option_value);
+							}
+						}
+						ONLY_OPTION_NAME => {
+							if slide_options.only.is_none() {
+								slide_options.only = option_value.trim().parse().ok();
+							}
+						}
+						NEAREST_THRESHOLD_OPTION_NAME => {
+							if slide_options.nearest_threshold.is_none() {
+								slide_options.nearest_threshold =
+									parse_non_negative_number(option_value);
 							}
 						}
 						_ => {}
 					}
+				} else if option == EMPTY_SLIDE_DIRECTIVE
+					&& paragraph.text.is_empty()
+					&& paragraph.content.is_none()
+				{
+					paragraph.content = Some(SlideContent::Empty);
+				}
+
+				continue;
+			}
+
+			// Further images in the paragraph of an image slide make it a grid of images
+			if let (Some(image_path), Some(SlideContent::Image(_) | SlideContent::Images(_))) = (
+				line_trimmed.strip_prefix(IMAGE_SLIDE_MARKER),
+				&paragraph.content,
+			) {
+				let (file_path, _) = split_image_anchor(image_path);
+				if !is_video_path(file_path) {
+					paragraph.content = match paragraph.content.take() {
+						Some(SlideContent::Image(first_file_path)) => {
+							Some(SlideContent::Images(vec![
+								first_file_path,
+								file_path.to_owned(),
+							]))
+						}
+						Some(SlideContent::Images(mut file_paths)) => {
+							file_paths.push(file_path.to_owned());
+							Some(SlideContent::Images(file_paths))
+						}
+						content => content,
+					};
 				}
 
 				continue;
 			}
 
-			// Skip comments and text following image slides
-			if line_trimmed.starts_with(COMMENT_MARKER) || skip_remainder_of_paragraph {
+			// Skip comments
+			if line_trimmed.starts_with(COMMENT_MARKER) {
+				continue;
+			}
+
+			// Keep text following image slides as their caption, and skip it for other
+			// slides
+			if let Some(content) = &paragraph.content {
+				if matches!(content, SlideContent::Image(_) | SlideContent::Images(_)) {
+					if !paragraph.text.is_empty() {
+						paragraph.text.push('\n');
+					}
+					paragraph.text.push_str(line_trimmed);
+				}
+
 				continue;
 			}
 
-			// Handle image slides
-			if current_paragraph.is_empty() && line_trimmed.starts_with(IMAGE_SLIDE_MARKER) {
-				slides.push(Slide::Image(line_trimmed[1..].to_owned()));
-				skip_remainder_of_paragraph = true;
+			// Handle image and video slides
+			if let Some(image_path) = line_trimmed
+				.strip_prefix(IMAGE_SLIDE_MARKER)
+				.filter(|_| paragraph.text.is_empty())
+			{
+				let (file_path, image_anchor) = split_image_anchor(image_path);
+				let file_path = file_path.to_owned();
+				if paragraph.slide_options.image_anchor.is_none() {
+					paragraph.slide_options.image_anchor = image_anchor;
+				}
+				if is_video_path(file_path.as_str()) {
+					paragraph.content = Some(SlideContent::Video(file_path));
+				} else {
+					paragraph.content = Some(SlideContent::Image(file_path));
+				}
 
 				continue;
 			}
 
 			// Remove the escape character if present
-			if line_trimmed.starts_with(ESCAPE_MARKER) {
-				line_trimmed = &line_trimmed[1..];
+			if let Some(unescaped_line) = line_trimmed.strip_prefix(ESCAPE_MARKER) {
+				line_trimmed = unescaped_line;
 			}
 
 			// If, after removing the escape character, the line is empty, this is an empty
 			// slide
 			if line_trimmed.is_empty() {
-				if current_paragraph.is_empty() {
-					slides.push(Slide::Empty);
-					skip_remainder_of_paragraph = true;
+				if paragraph.text.is_empty() {
+					paragraph.content = Some(SlideContent::Empty);
 				}
 
 				continue;
 			}
 
 			// Insert a line separator
-			if !current_paragraph.is_empty() {
-				current_paragraph.push('\n');
+			if !paragraph.text.is_empty() {
+				paragraph.text.push('\n');
 			}
-			current_paragraph.push_str(line_trimmed);
+			paragraph.text.push_str(line_trimmed);
 		}
 
-		if !current_paragraph.is_empty() {
-			slides.push(Slide::Text(current_paragraph));
+		paragraph.finish(&mut slides, &mut default_slide_options);
+
+		if normalise.unwrap_or(false) {
+			for slide in &mut slides {
+				if let SlideContent::Text(text) = &mut slide.content {
+					*text = text.nfc().collect();
+				}
+			}
 		}
 
 		// Ensure the presentation always has at least one slide
-		if slides.is_empty() {
-			slides.push(Slide::Empty);
+		// Included files are left to provide it, since they're spliced in later
+		if slides.is_empty() && includes.is_empty() {
+			slides.push(SlideContent::Empty.into());
 		}
 
 		// Construct the final result
-		Self {
+		let presentation = Self {
+			title,
 			font_list,
 			foreground_colour,
+			foreground_palette,
+			foreground_mode,
 			background_colour,
+			stage_colour,
+			blank_colour,
+			dark_theme,
+			light_theme,
+			fit_mode,
+			fit_overflow,
+			animations,
+			mirror,
+			opacity,
+			kiosk,
+			bullets,
+			idle_reset,
+			text_outline_colour,
+			text_outline_width,
+			horizontal_margin,
+			vertical_margin,
+			watermark,
+			watermark_position,
+			watermark_opacity,
+			maximum_image_size,
+			countdown,
+			text_rendering,
+			shuffle,
+			touch_split,
+			navigation_sound,
+			show_slide_numbers,
+			show_progress_bar,
+			placeholders,
+			looping,
+			default_slide_options,
 			slides,
-		}
+		};
+
+		(presentation, includes)
 	}
 
-	pub fn load_from_path<P>(path: P) -> Result<Self, String>
+	/// Loads a presentation from a file, resolving the paths of its images
+	/// and videos relative to it, and splicing in the files it includes.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the file or any of the files it includes can't be
+	/// read, if it includes itself, or if any of the images it refers to by
+	/// wildcards or directories can't be found.
+	pub fn load_from_path<P>(path: P) -> Result<Self, ParseError>
 	where
 		P: AsRef<Path>,
 	{
-		let path = path.as_ref();
-		let file_contents = read_to_string(path).map_err(|_| {
-			format!(
-				"unable to read the presentation file\n\"{}\"!",
-				path.to_string_lossy()
-			)
-		})?;
-
-		Ok(Self::load(file_contents.as_str()))
+		Self::load_from_path_including(path.as_ref(), &mut Vec::new())
 	}
 
-	pub fn try_get_title(&self) -> Option<String> {
-		const MAXIMUM_TITLE_LENGTH: usize = 64;
-		const ELLIPSIS: char = '\u{2026}';
-
-		self.slides.iter().find_map(|slide| match slide {
-			Slide::Text(text) => {
-				// Since the user is expected to wrap the text on their own, newlines need to be
-				// converted to spaces so the slide contents are on one long line
-				// The trimming is to prevent having multiple spaces in the title, which looks
-				// ugly
-				let mut title_text = String::with_capacity(text.len());
-				for line in text.lines().map(str::trim) {
-					if !title_text.is_empty() {
-						title_text.push(' ');
-					}
-					title_text.push_str(line);
-				}
-
-				// Truncate to the maximum length and put an ellipsis on the end if so
-				if char_truncate(&mut title_text, MAXIMUM_TITLE_LENGTH - 1) {
-					title_text.push(ELLIPSIS);
-				}
+	/// Loads a presentation from a file as with [`load_from_path`].
+	///
+	/// `including_paths` are the canonical paths of the files that are
+	/// including this one, which are used to detect include cycles.
+	///
+	/// [`load_from_path`]: Self::load_from_path
+	fn load_from_path_including(
+		path: &Path,
+		including_paths: &mut Vec<PathBuf>,
+	) -> Result<Self, ParseError> {
+		let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_owned());
+		if including_paths.contains(&canonical_path) {
+			return Err(ParseError::IncludeCycle {
+				path: path.to_owned(),
+			});
+		}
+		if path.is_dir() {
+			return Err(ParseError::Directory {
+				path: path.to_owned(),
+			});
+		}
+		let file_contents =
+			read(path)
+				.and_then(decode_text)
+				.map_err(|error| ParseError::UnreadableFile {
+					path: path.to_owned(),
+					error,
+				})?;
 
-				Some(title_text)
-			}
-			Slide::Image(_) | Slide::Empty => None,
-		})
-	}
-}
+		let (mut presentation, includes) = Self::parse(file_contents.as_str());
+		let base_path = path.parent().unwrap_or_else(|| Path::new(""));
+		if includes.is_empty() {
+			presentation.resolve_media_paths(base_path)?;
 
-impl Default for Presentation {
-	fn default() -> Self {
-		Self {
-			font_list:         vec![],
-			foreground_colour: None,
-			background_colour: None,
-			slides:            vec![Slide::Empty],
+			return Ok(presentation);
 		}
-	}
-}
 
-impl From<String> for Presentation {
-	fn from(value: String) -> Self {
-		Self {
-			slides: vec![Slide::Text(value)],
-			..Default::default()
+		// The slides between the included files are resolved separately, since the
+		// included slides are already resolved relative to their own files
+		presentation.resolve_option_paths(base_path);
+		including_paths.push(canonical_path);
+		let mut own_slides = take(&mut presentation.slides).into_iter();
+		let mut previous_slide_index = 0;
+		for (slide_index, include_path) in includes {
+			presentation.slides.extend(resolve_slide_media_paths(
+				own_slides
+					.by_ref()
+					.take(slide_index - previous_slide_index)
+					.collect(),
+				base_path,
+			)?);
+			previous_slide_index = slide_index;
+
+			let mut included_presentation =
+				Self::load_from_path_including(&base_path.join(include_path), including_paths)
+					.map_err(|error| ParseError::InInclude {
+						error:          Box::new(error),
+						including_path: path.to_owned(),
+					})?;
+			// The included file's own defaults still apply to its slides
+			let mut included_slides = take(&mut included_presentation.slides);
+			for slide in &mut included_slides {
+				slide
+					.options
+					.merge(&included_presentation.default_slide_options);
+			}
+			included_presentation.default_slide_options = SlideOptions::default();
+			presentation.append(included_presentation);
+			presentation.slides.extend(included_slides);
 		}
+		including_paths.pop();
+		presentation
+			.slides
+			.extend(resolve_slide_media_paths(own_slides.collect(), base_path)?);
+		if presentation.slides.is_empty() {
+			presentation.slides.push(SlideContent::Empty.into());
+		}
+
+		Ok(presentation)
 	}
-}
 
-fn parse_colour_hex_code(mut hex_value: &str) -> Option<LinearRgbaColour> {
-	const HEX_CODE_MARKER: char = '#';
-	const HEX_RADIX: u32 = 0x10;
-	const EXPECTED_LENGTH: usize = 3 * 2;
-	const OPAQUE_ALPHA_VALUE: f32 = 1.0;
+	/// Resolves the paths of the images and videos relative to a base path,
+	/// expanding any that refer to multiple files.
+	///
+	/// This keeps the paths correct even if the presentation is combined with
+	/// others.
+	fn resolve_media_paths(&mut self, base_path: &Path) -> Result<(), ParseError> {
+		self.resolve_option_paths(base_path);
+		self.slides = resolve_slide_media_paths(take(&mut self.slides), base_path)?;
 
-	fn parse_single_channel(channel_hex_value: &str) -> Option<f32> {
-		let parsed_value = u8::from_str_radix(channel_hex_value, HEX_RADIX).ok()?;
-		let srgb_value = f32::from(parsed_value) / f32::from(u8::MAX);
-		let linear_rgb_value = srgb_to_linear_rgb_channel(srgb_value);
+		Ok(())
+	}
 
-		Some(linear_rgb_value)
+	/// Resolves the paths of the watermark and the default background image
+	/// relative to a base path.
+	fn resolve_option_paths(&mut self, base_path: &Path) {
+		if let Some(watermark) = &mut self.watermark {
+			*watermark = base_path.join(&watermark).to_string_lossy().into_owned();
+		}
+		if let Some(background_image) = &mut self.default_slide_options.background_image {
+			*background_image = base_path
+				.join(&background_image)
+				.to_string_lossy()
+				.into_owned();
+		}
 	}
 
-	// Remove the leading marker character if present
+	/// Loads multiple presentation files and concatenates them into one, in the
+	/// order provided.
+	///
+	/// See [`append`] for how the options of each file are merged.
+	///
+	/// # Errors
+	///
+	/// Returns an error if any of the files can't be loaded, as with
+	/// [`load_from_path`].
+	///
+	/// [`append`]: Self::append
+	/// [`load_from_path`]: Self::load_from_path
+	pub fn load_from_paths<P>(paths: &[P]) -> Result<Self, ParseError>
+	where
+		P: AsRef<Path>,
+	{
+		let mut paths = paths.iter();
+		let Some(first_path) = paths.next() else {
+			return Ok(Self::default());
+		};
+
+		let mut presentation = Self::load_from_path(first_path)?;
+		for path in paths {
+			presentation.append(Self::load_from_path(path)?);
+		}
+
+		Ok(presentation)
+	}
+
+	/// Loads the presentation files listed in a manifest and concatenates them
+	/// into one, in the order listed.
+	///
+	/// Each line of the manifest is either the path of a presentation file, an
+	/// image or video slide written as in a presentation file, a comment, or
+	/// blank, which adds an empty slide as a break. Paths are relative to the
+	/// manifest.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the manifest can't be read, or if any of the files
+	/// it lists can't be loaded.
+	pub fn load_from_manifest<P>(path: P) -> Result<Self, ParseError>
+	where
+		P: AsRef<Path>,
+	{
+		let path = path.as_ref();
+		let manifest_contents =
+			read(path)
+				.and_then(decode_text)
+				.map_err(|error| ParseError::UnreadableManifest {
+					path: path.to_owned(),
+					error,
+				})?;
+		let base_path = path.parent().unwrap_or_else(|| Path::new(""));
+
+		let mut presentation: Option<Self> = None;
+		for (line_number, entry) in parse_manifest(manifest_contents.as_str()) {
+			let entry_presentation = match entry {
+				ManifestEntry::File(file_path) => Self::load_from_path(base_path.join(file_path)),
+				ManifestEntry::Slide(slide_line) => {
+					let mut slide_presentation = Self::load(slide_line);
+					slide_presentation
+						.resolve_media_paths(base_path)
+						.map(|()| slide_presentation)
+				}
+				ManifestEntry::Break => Ok(Self::default()),
+			}
+			.map_err(|error| ParseError::InManifest {
+				error: Box::new(error),
+				line_number,
+				manifest_path: path.to_owned(),
+			})?;
+
+			if let Some(presentation) = &mut presentation {
+				presentation.append(entry_presentation);
+			} else {
+				presentation = Some(entry_presentation);
+			}
+		}
+
+		Ok(presentation.unwrap_or_default())
+	}
+
+	/// Appends the slides of another presentation to the end of this one.
+	///
+	/// Options are merged with the earlier presentation taking precedence,
+	/// matching how repeated options behave within a single file:
+	/// - The font lists are concatenated, so the earlier fonts are searched
+	///   first.
+	/// - All other options are taken from the first presentation that sets
+	///   them.
+	pub fn append(&mut self, other: Self) {
+		self.title = self.title.take().or(other.title);
+		self.font_list.extend(other.font_list);
+		self.foreground_colour = self.foreground_colour.or(other.foreground_colour);
+		self.foreground_palette.extend(other.foreground_palette);
+		self.foreground_mode = self.foreground_mode.or(other.foreground_mode);
+		self.background_colour = self.background_colour.or(other.background_colour);
+		self.stage_colour = self.stage_colour.or(other.stage_colour);
+		self.blank_colour = self.blank_colour.or(other.blank_colour);
+		self.dark_theme = self.dark_theme.or(other.dark_theme);
+		self.light_theme = self.light_theme.or(other.light_theme);
+		self.fit_mode = self.fit_mode.or(other.fit_mode);
+		self.fit_overflow = self.fit_overflow.or(other.fit_overflow);
+		self.animations = self.animations.or(other.animations);
+		self.mirror = self.mirror.or(other.mirror);
+		self.opacity = self.opacity.or(other.opacity);
+		self.kiosk = self.kiosk.or(other.kiosk);
+		self.bullets = self.bullets.or(other.bullets);
+		self.idle_reset = self.idle_reset.or(other.idle_reset);
+		self.text_outline_colour = self.text_outline_colour.or(other.text_outline_colour);
+		self.text_outline_width = self.text_outline_width.or(other.text_outline_width);
+		self.horizontal_margin = self.horizontal_margin.or(other.horizontal_margin);
+		self.vertical_margin = self.vertical_margin.or(other.vertical_margin);
+		self.watermark = self.watermark.take().or(other.watermark);
+		self.watermark_position = self.watermark_position.or(other.watermark_position);
+		self.watermark_opacity = self.watermark_opacity.or(other.watermark_opacity);
+		self.maximum_image_size = self.maximum_image_size.or(other.maximum_image_size);
+		self.countdown = self.countdown.or(other.countdown);
+		self.text_rendering = self.text_rendering.or(other.text_rendering);
+		self.shuffle = self.shuffle.or(other.shuffle);
+		self.touch_split = self.touch_split.or(other.touch_split);
+		self.navigation_sound = self.navigation_sound.or(other.navigation_sound);
+		self.show_slide_numbers = self.show_slide_numbers.or(other.show_slide_numbers);
+		self.show_progress_bar = self.show_progress_bar.or(other.show_progress_bar);
+		self.placeholders = self.placeholders.or(other.placeholders);
+		self.looping = self.looping.or(other.looping);
+		self.default_slide_options
+			.merge(&other.default_slide_options);
+		self.slides.extend(other.slides);
+	}
+
+	/// Replaces the placeholders in text slides with their values, if they're
+	/// enabled.
+	///
+	/// The date and time are in UTC, at the time provided. Unrecognised
+	/// placeholders are left as they are.
+	///
+	/// This should be done once the slides are final, since the slide count is
+	/// based on them.
+	pub fn substitute_placeholders(&mut self, seconds_since_epoch: u64) {
+		if self.placeholders != Some(true) {
+			return;
+		}
+
+		let (year, month, day) = civil_date(seconds_since_epoch / SECONDS_PER_DAY);
+		let seconds_of_day = seconds_since_epoch % SECONDS_PER_DAY;
+		let values = [
+			(DATE_PLACEHOLDER, format!("{year:04}-{month:02}-{day:02}")),
+			(
+				TIME_PLACEHOLDER,
+				format!(
+					"{:02}:{:02}",
+					seconds_of_day / 3600,
+					seconds_of_day / 60 % 60
+				),
+			),
+			(SLIDE_COUNT_PLACEHOLDER, self.slides.len().to_string()),
+		];
+		for slide in &mut self.slides {
+			if let SlideContent::Text(text) = &mut slide.content {
+				for (placeholder, value) in &values {
+					*text = text.replace(placeholder, value);
+				}
+			}
+		}
+	}
+
+	/// Gives each text slide the next colour in the foreground palette, if the
+	/// foreground mode is set to cycle through it.
+	///
+	/// This should be done once the slides are final, since it's based on
+	/// their positions. Only text slides are counted, so other slides don't use
+	/// up any colours.
+	pub fn cycle_foreground_colours(&mut self) {
+		if self.foreground_mode != Some(ForegroundMode::Cycle) || self.foreground_palette.is_empty()
+		{
+			return;
+		}
+
+		let text_slides = self
+			.slides
+			.iter_mut()
+			.filter(|slide| matches!(slide.content, SlideContent::Text(_)));
+		for (text_slide_index, slide) in text_slides.enumerate() {
+			slide.options.foreground_colour = slide.options.foreground_colour.or(Some(
+				self.foreground_palette[text_slide_index % self.foreground_palette.len()],
+			));
+		}
+	}
+
+	/// Removes the slides that are limited to outputs other than the provided
+	/// one.
+	///
+	/// If that leaves no slides, a single empty slide is left in their place,
+	/// so that the presentation always has at least one slide.
+	pub fn retain_slides_for(&mut self, output: Output) {
+		let default_only = self.default_slide_options.only;
+		self.slides.retain(|slide| {
+			slide
+				.options
+				.only
+				.or(default_only)
+				.map_or(true, |only| only == output)
+		});
+		if self.slides.is_empty() {
+			self.slides.push(SlideContent::Empty.into());
+		}
+	}
+
+	/// The paths of the background images used by the slides, including the
+	/// default one.
+	pub fn background_image_paths(&self) -> impl Iterator<Item = &String> {
+		self.default_slide_options.background_image.iter().chain(
+			self.slides
+				.iter()
+				.filter_map(|slide| slide.options.background_image.as_ref()),
+		)
+	}
+
+	/// Finds the index of the slide with the provided anchor.
+	#[must_use]
+	pub fn find_anchor(&self, anchor: &str) -> Option<usize> {
+		self.slides
+			.iter()
+			.position(|slide| slide.anchor.as_deref() == Some(anchor))
+	}
+
+	/// Finds the index of the first text slide that contains the query,
+	/// ignoring case, starting from a slide and moving forward, or backward if
+	/// `forward` is `false`.
+	///
+	/// The search wraps around, so every slide is searched.
+	#[must_use]
+	pub fn find_text(&self, query: &str, start_slide: usize, forward: bool) -> Option<usize> {
+		let slide_count = self.slides.len();
+		let query = query.to_lowercase();
+
+		(0..slide_count)
+			.map(|offset| {
+				if forward {
+					(start_slide + offset) % slide_count
+				} else {
+					(start_slide + slide_count - offset) % slide_count
+				}
+			})
+			.find(|&slide_index| match &self.slides[slide_index].content {
+				// Colour tags aren't part of the text that's shown, and lines are searched as
+				// if they were joined, since the query can't contain newlines
+				SlideContent::Text(text) => split_colour_spans(text)
+					.into_iter()
+					.map(|span| span.text)
+					.collect::<String>()
+					.replace('\n', " ")
+					.to_lowercase()
+					.contains(query.as_str()),
+				SlideContent::Image(_)
+				| SlideContent::Images(_)
+				| SlideContent::Video(_)
+				| SlideContent::Empty => false,
+			})
+	}
+
+	/// The title of the presentation, which is the one set explicitly if there
+	/// is one, or else the text of the first text slide.
+	#[must_use]
+	pub fn try_get_title(&self) -> Option<String> {
+		const MAXIMUM_TITLE_LENGTH: usize = 64;
+
+		if let Some(title) = &self.title {
+			return Some(to_single_line_preview(title, MAXIMUM_TITLE_LENGTH));
+		}
+
+		self.slides.iter().find_map(|slide| match &slide.content {
+			SlideContent::Text(text) => Some(to_single_line_preview(text, MAXIMUM_TITLE_LENGTH)),
+			SlideContent::Image(_)
+			| SlideContent::Images(_)
+			| SlideContent::Video(_)
+			| SlideContent::Empty => None,
+		})
+	}
+}
+
+impl SlideContent {
+	/// A short, single-line summary of the content, such as for listing the
+	/// slides of a presentation.
+	#[must_use]
+	pub fn summarise(&self) -> String {
+		const MAXIMUM_PREVIEW_LENGTH: usize = 40;
+
+		match self {
+			Self::Text(text) => format!(
+				"Text (\"{}\")",
+				to_single_line_preview(text, MAXIMUM_PREVIEW_LENGTH)
+			),
+			Self::Image(image_path) => format!("Image ({image_path})"),
+			Self::Images(image_paths) => format!("Images ({})", image_paths.join(", ")),
+			Self::Video(video_path) => format!("Video ({video_path})"),
+			Self::Empty => "Empty".to_owned(),
+		}
+	}
+
+	/// The paths of the images shown by the content, if any.
+	#[must_use]
+	pub fn image_paths(&self) -> &[String] {
+		match self {
+			Self::Image(image_path) => slice::from_ref(image_path),
+			Self::Images(image_paths) => image_paths.as_slice(),
+			Self::Text(_) | Self::Video(_) | Self::Empty => &[],
+		}
+	}
+}
+
+impl Default for Presentation {
+	fn default() -> Self {
+		Self {
+			title:                 None,
+			font_list:             vec![],
+			foreground_colour:     None,
+			foreground_palette:    Vec::new(),
+			foreground_mode:       None,
+			background_colour:     None,
+			stage_colour:          None,
+			blank_colour:          None,
+			dark_theme:            None,
+			light_theme:           None,
+			fit_mode:              None,
+			fit_overflow:          None,
+			animations:            None,
+			mirror:                None,
+			opacity:               None,
+			kiosk:                 None,
+			bullets:               None,
+			idle_reset:            None,
+			text_outline_colour:   None,
+			text_outline_width:    None,
+			horizontal_margin:     None,
+			vertical_margin:       None,
+			watermark:             None,
+			watermark_position:    None,
+			watermark_opacity:     None,
+			maximum_image_size:    None,
+			countdown:             None,
+			text_rendering:        None,
+			shuffle:               None,
+			touch_split:           None,
+			navigation_sound:      None,
+			show_slide_numbers:    None,
+			show_progress_bar:     None,
+			placeholders:          None,
+			looping:               None,
+			default_slide_options: SlideOptions::default(),
+			slides:                vec![SlideContent::Empty.into()],
+		}
+	}
+}
+
+impl From<String> for Presentation {
+	fn from(value: String) -> Self {
+		Self {
+			slides: vec![SlideContent::Text(value).into()],
+			..Default::default()
+		}
+	}
+}
+
+impl Slide {
+	/// The number of steps the slide is shown in, which is one for each line of
+	/// text shown if its lines are revealed one at a time.
+	#[must_use]
+	pub fn reveal_step_count(&self, default_slide_options: &SlideOptions) -> usize {
+		match &self.content {
+			SlideContent::Text(text)
+				if self
+					.options
+					.reveal
+					.or(default_slide_options.reveal)
+					.unwrap_or(false) =>
+			{
+				let maximum_lines = self
+					.options
+					.maximum_lines
+					.or(default_slide_options.maximum_lines)
+					.unwrap_or(usize::MAX);
+
+				text.lines().count().clamp(1, maximum_lines)
+			}
+			SlideContent::Text(_)
+			| SlideContent::Image(_)
+			| SlideContent::Images(_)
+			| SlideContent::Video(_)
+			| SlideContent::Empty => 1,
+		}
+	}
+
+	/// Whether the slide is a backup slide, which is left out of the
+	/// presentation's normal flow.
+	#[must_use]
+	pub fn is_backup(&self, default_slide_options: &SlideOptions) -> bool {
+		self.options
+			.backup
+			.or(default_slide_options.backup)
+			.unwrap_or(false)
+	}
+
+	/// How long the slide is shown before the presentation advances by
+	/// itself, if it does.
+	#[must_use]
+	pub fn advance_duration(&self, default_slide_options: &SlideOptions) -> Option<Duration> {
+		self.options.advance.or(default_slide_options.advance)
+	}
+}
+
+impl From<SlideContent> for Slide {
+	fn from(content: SlideContent) -> Self {
+		Self {
+			content,
+			options: SlideOptions::default(),
+			anchor: None,
+			caption: None,
+		}
+	}
+}
+
+impl SlideOptions {
+	/// Fills in any options that aren't set with the values from `other`.
+	pub fn merge(&mut self, other: &Self) {
+		self.vertical_alignment = self.vertical_alignment.or(other.vertical_alignment);
+		self.horizontal_alignment = self.horizontal_alignment.or(other.horizontal_alignment);
+		self.font_size = self.font_size.or(other.font_size);
+		self.image_anchor = self.image_anchor.or(other.image_anchor);
+		self.slide_type = self.slide_type.or(other.slide_type);
+		self.scroll_duration = self.scroll_duration.or(other.scroll_duration);
+		self.advance = self.advance.or(other.advance);
+		self.nearest_threshold = self.nearest_threshold.or(other.nearest_threshold);
+		self.subtitle_scale = self.subtitle_scale.or(other.subtitle_scale);
+		self.scale = self.scale.or(other.scale);
+		self.only = self.only.or(other.only);
+		self.reveal = self.reveal.or(other.reveal);
+		self.maximum_lines = self.maximum_lines.or(other.maximum_lines);
+		self.foreground_colour = self.foreground_colour.or(other.foreground_colour);
+		self.margins = self.margins.or(other.margins);
+		if self.background_image.is_none() {
+			self.background_image = other.background_image.clone();
+		}
+		self.backup = self.backup.or(other.backup);
+		self.captions = self.captions.or(other.captions);
+	}
+}
+
+/// A line of a manifest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ManifestEntry<'a> {
+	/// The path of a presentation file.
+	File(&'a str),
+	/// An image or video slide.
+	Slide(&'a str),
+	/// An empty slide.
+	Break,
+}
+
+/// Parses the contents of a manifest, returning each entry along with its
+/// line number.
+fn parse_manifest(contents: &str) -> Vec<(usize, ManifestEntry)> {
+	contents
+		.lines()
+		.enumerate()
+		.filter_map(|(line_index, line)| {
+			let line = line.trim();
+			let entry = if line.is_empty() {
+				ManifestEntry::Break
+			} else if line.starts_with(COMMENT_MARKER) {
+				return None;
+			} else if line.starts_with(IMAGE_SLIDE_MARKER) {
+				ManifestEntry::Slide(line)
+			} else {
+				ManifestEntry::File(line)
+			};
+
+			Some((line_index + 1, entry))
+		})
+		.collect()
+}
+
+/// The state of the paragraph that's currently being parsed.
+#[derive(Default)]
+struct Paragraph {
+	text:          String,
+	/// The content of the slide, if it was determined before the end of the
+	/// paragraph, as with image slides.
+	///
+	/// Once this is set, the remainder of the paragraph is ignored.
+	content:       Option<SlideContent>,
+	slide_options: SlideOptions,
+	anchor:        Option<String>,
+}
+
+impl Paragraph {
+	/// Completes the paragraph, adding its slide if it has one.
+	///
+	/// If it doesn't, any slide options it set become the defaults for all
+	/// slides, and its anchor is carried over to the next paragraph.
+	fn finish(&mut self, slides: &mut Vec<Slide>, default_slide_options: &mut SlideOptions) {
+		let paragraph = take(self);
+
+		let text = (!paragraph.text.is_empty()).then_some(paragraph.text);
+		let (content, caption) = match paragraph.content {
+			Some(content @ (SlideContent::Image(_) | SlideContent::Images(_))) => {
+				(Some(content), text)
+			}
+			Some(content) => (Some(content), None),
+			None => (text.map(SlideContent::Text), None),
+		};
+		if let Some(content) = content {
+			slides.push(Slide {
+				content,
+				options: paragraph.slide_options,
+				anchor: paragraph.anchor,
+				caption,
+			});
+		} else {
+			default_slide_options.merge(&paragraph.slide_options);
+			self.anchor = paragraph.anchor;
+		}
+	}
+}
+
+impl FromStr for FitMode {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"width" => Ok(Self::Width),
+			"height" => Ok(Self::Height),
+			"both" => Ok(Self::Both),
+			_ => Err(()),
+		}
+	}
+}
+
+impl FromStr for FitOverflow {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"clip" => Ok(Self::Clip),
+			"start" => Ok(Self::Start),
+			_ => Err(()),
+		}
+	}
+}
+
+impl FromStr for TextRendering {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"smooth" => Ok(Self::Smooth),
+			"precise" => Ok(Self::Precise),
+			"sharp" => Ok(Self::Sharp),
+			_ => Err(()),
+		}
+	}
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+	match value.trim() {
+		"true" => Some(true),
+		"false" => Some(false),
+		_ => None,
+	}
+}
+
+/// Parses a number that must be positive, such as a size or duration.
+fn parse_positive_number(value: &str) -> Option<f32> {
+	let number = value.trim().parse::<f32>().ok()?;
+
+	(number.is_finite() && number > 0.0).then_some(number)
+}
+
+/// Splits the text of a text slide into runs, based on
+/// `[color=#rrggbb]...[/color]` tags.
+///
+/// Tags that aren't matched or that have an invalid colour are kept as part of
+/// the text.
+#[must_use]
+pub fn split_colour_spans(text: &str) -> Vec<TextSpan> {
+	let mut spans = Vec::new();
+	let mut literal_start = 0;
+	let mut search_start = 0;
+
+	while let Some(tag_offset) = text[search_start..].find(COLOUR_SPAN_START) {
+		let tag_start = search_start + tag_offset;
+		let colour_start = tag_start + COLOUR_SPAN_START.len();
+		let span = text[colour_start..]
+			.split_once(COLOUR_SPAN_TAG_END)
+			.and_then(|(colour_value, remainder)| {
+				let colour = parse_colour(colour_value)?;
+				let (span_text, _) = remainder.split_once(COLOUR_SPAN_END)?;
+
+				Some((colour_value.len(), colour, span_text))
+			});
+		let Some((colour_value_length, colour, span_text)) = span else {
+			search_start = colour_start;
+			continue;
+		};
+
+		if literal_start < tag_start {
+			spans.push(TextSpan {
+				text:   &text[literal_start..tag_start],
+				colour: None,
+			});
+		}
+		if !span_text.is_empty() {
+			spans.push(TextSpan {
+				text:   span_text,
+				colour: Some(colour),
+			});
+		}
+
+		let span_end = colour_start
+			+ colour_value_length
+			+ COLOUR_SPAN_TAG_END.len_utf8()
+			+ span_text.len()
+			+ COLOUR_SPAN_END.len();
+		literal_start = span_end;
+		search_start = span_end;
+	}
+
+	// Any remaining text is uncoloured, and the text is always kept whole if there
+	// would otherwise be nothing left to show
+	if literal_start < text.len() || spans.is_empty() {
+		spans.push(TextSpan {
+			text:   &text[literal_start..],
+			colour: None,
+		});
+	}
+
+	spans
+}
+
+/// Decodes the contents of a text file, which are assumed to be UTF-8 unless
+/// they start with a byte order mark for another encoding, such as UTF-16.
+///
+/// Returns an error of the kind [`ErrorKind::InvalidData`] if the contents
+/// aren't valid in their encoding.
+pub(crate) fn decode_text(bytes: Vec<u8>) -> io::Result<String> {
+	let Some((encoding, byte_order_mark_length)) = Encoding::for_bom(&bytes) else {
+		return String::from_utf8(bytes)
+			.map_err(|error| io::Error::new(ErrorKind::InvalidData, error));
+	};
+
+	let (text, had_errors) = encoding.decode_without_bom_handling(&bytes[byte_order_mark_length..]);
+	if had_errors {
+		return Err(io::Error::new(
+			ErrorKind::InvalidData,
+			format!("the text isn't valid {}", encoding.name()),
+		));
+	}
+
+	Ok(text.into_owned())
+}
+
+/// Joins the lines of some text with spaces and truncates it to a maximum
+/// length, putting an ellipsis on the end if it was truncated.
+fn to_single_line_preview(text: &str, maximum_length: usize) -> String {
+	// Since the user is expected to wrap the text on their own, newlines need to be
+	// converted to spaces so the text is on one long line
+	// The trimming is to prevent having multiple spaces in the preview, which looks
+	// ugly
+	// Colour tags aren't part of the text that's shown
+	let text = split_colour_spans(text)
+		.into_iter()
+		.map(|span| span.text)
+		.collect::<String>();
+
+	let mut preview = String::with_capacity(text.len());
+	for line in text.lines().map(str::trim) {
+		if !preview.is_empty() {
+			preview.push(' ');
+		}
+		preview.push_str(line);
+	}
+
+	// Truncate to the maximum length and put an ellipsis on the end if so
+	if char_truncate(&mut preview, maximum_length - 1) {
+		preview.push(ELLIPSIS);
+	}
+
+	preview
+}
+
+/// Parses a number that must not be negative, such as a threshold.
+fn parse_non_negative_number(value: &str) -> Option<f32> {
+	let number = value.trim().parse::<f32>().ok()?;
+
+	(number.is_finite() && number >= 0.0).then_some(number)
+}
+
+/// Parses a fraction, such as an opacity, which must be between `0.0` and
+/// `1.0`.
+fn parse_fraction(value: &str) -> Option<f32> {
+	let fraction = value.trim().parse::<f32>().ok()?;
+
+	(0.0..=1.0).contains(&fraction).then_some(fraction)
+}
+
+/// Parses a duration in seconds, which must be longer than zero and short
+/// enough to be represented.
+fn parse_seconds(value: &str) -> Option<Duration> {
+	Duration::try_from_secs_f32(parse_positive_number(value)?).ok()
+}
+
+/// Parses an auto-advance duration in seconds, with an optional `s` suffix,
+/// which must be longer than zero.
+fn parse_advance(value: &str) -> Option<Duration> {
+	let value = value.trim();
+
+	parse_seconds(value.strip_suffix('s').unwrap_or(value))
+}
+
+/// Parses a countdown duration in the format `mm:ss`, which must be longer
+/// than zero.
+fn parse_countdown(value: &str) -> Option<Duration> {
+	parse_minutes_and_seconds(value.trim()).filter(|duration| !duration.is_zero())
+}
+
+/// Parses a margin, which must leave at least a fifth of the screen usable.
+fn parse_margin(value: &str) -> Option<f32> {
+	const MAXIMUM_MARGIN: f32 = 0.4;
+
+	let margin = value.trim().parse::<f32>().ok()?;
+
+	(0.0..=MAXIMUM_MARGIN).contains(&margin).then_some(margin)
+}
+
+/// Parses the margins of a slide, which are either a single margin for both
+/// directions, or a horizontal and vertical margin separated by a comma.
+fn parse_slide_margins(value: &str) -> Option<(f32, f32)> {
+	match value.split_once(',') {
+		Some((horizontal_margin, vertical_margin)) => Some((
+			parse_margin(horizontal_margin)?,
+			parse_margin(vertical_margin)?,
+		)),
+		None => parse_margin(value).map(|margin| (margin, margin)),
+	}
+}
+
+/// Parses a theme, which is a foreground and background colour separated by
+/// whitespace.
+fn parse_theme(value: &str) -> Option<Theme> {
+	let mut colours = value.split_whitespace();
+	let theme = Theme {
+		foreground_colour: parse_colour(colours.next()?)?,
+		background_colour: parse_colour(colours.next()?)?,
+	};
+
+	colours.next().is_none().then_some(theme)
+}
+
+impl FromStr for SlideType {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"static" => Ok(Self::Static),
+			"scroll" => Ok(Self::Scroll),
+			"title" => Ok(Self::Title),
+			_ => Err(()),
+		}
+	}
+}
+
+impl FromStr for ForegroundMode {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"single" => Ok(Self::Single),
+			"cycle" => Ok(Self::Cycle),
+			_ => Err(()),
+		}
+	}
+}
+
+impl FromStr for Output {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"presenter" => Ok(Self::Presenter),
+			"audience" => Ok(Self::Audience),
+			"export" => Ok(Self::Export),
+			_ => Err(()),
+		}
+	}
+}
+
+impl FromStr for Mirror {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"horizontal" => Ok(Self::Horizontal),
+			"vertical" => Ok(Self::Vertical),
+			_ => Err(()),
+		}
+	}
+}
+
+impl FromStr for VerticalAlignment {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"top" => Ok(Self::Top),
+			"center" | "middle" => Ok(Self::Center),
+			"bottom" => Ok(Self::Bottom),
+			_ => Err(()),
+		}
+	}
+}
+
+impl FromStr for HorizontalAlignment {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"left" => Ok(Self::Left),
+			"center" | "middle" => Ok(Self::Center),
+			"right" => Ok(Self::Right),
+			_ => Err(()),
+		}
+	}
+}
+
+impl Anchor {
+	/// The position of the anchor along each axis, from `0.0` at the top-left
+	/// to `1.0` at the bottom-right.
+	#[must_use]
+	pub fn to_fractions(self) -> (f32, f32) {
+		match self {
+			Self::TopLeft => (0.0, 0.0),
+			Self::Top => (0.5, 0.0),
+			Self::TopRight => (1.0, 0.0),
+			Self::Left => (0.0, 0.5),
+			Self::Center => (0.5, 0.5),
+			Self::Right => (1.0, 0.5),
+			Self::BottomLeft => (0.0, 1.0),
+			Self::Bottom => (0.5, 1.0),
+			Self::BottomRight => (1.0, 1.0),
+		}
+	}
+}
+
+impl FromStr for Anchor {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"top-left" => Ok(Self::TopLeft),
+			"top" => Ok(Self::Top),
+			"top-right" => Ok(Self::TopRight),
+			"left" => Ok(Self::Left),
+			"center" | "middle" => Ok(Self::Center),
+			"right" => Ok(Self::Right),
+			"bottom-left" => Ok(Self::BottomLeft),
+			"bottom" => Ok(Self::Bottom),
+			"bottom-right" => Ok(Self::BottomRight),
+			_ => Err(()),
+		}
+	}
+}
+
+/// Splits the anchor off the end of an image slide's file path, if it has one
+/// (eg. `image.png@top-left`).
+///
+/// If what follows the last marker isn't a valid anchor, it's treated as part
+/// of the file path.
+fn split_image_anchor(value: &str) -> (&str, Option<Anchor>) {
+	value
+		.rsplit_once(IMAGE_SLIDE_MARKER)
+		.and_then(|(file_path, anchor)| Some((file_path, Some(anchor.parse().ok()?))))
+		.unwrap_or((value, None))
+}
+
+impl ThemePreset {
+	/// The colours of the preset.
+	#[must_use]
+	pub fn to_theme(self) -> Theme {
+		// The foreground and background colours, in sRGB
+		let (foreground_colour, background_colour) = match self {
+			Self::SolarizedDark => ([0x83, 0x94, 0x96], [0x00, 0x2B, 0x36]),
+			Self::SolarizedLight => ([0x65, 0x7B, 0x83], [0xFD, 0xF6, 0xE3]),
+			Self::Dracula => ([0xF8, 0xF8, 0xF2], [0x28, 0x2A, 0x36]),
+			Self::Mono => ([0x00, 0x00, 0x00], [0xFF, 0xFF, 0xFF]),
+		};
+
+		Theme {
+			foreground_colour: srgb_to_linear_rgba_colour(foreground_colour),
+			background_colour: srgb_to_linear_rgba_colour(background_colour),
+		}
+	}
+}
+
+impl FromStr for ThemePreset {
+	type Err = ();
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value {
+			"solarized-dark" => Ok(Self::SolarizedDark),
+			"solarized-light" => Ok(Self::SolarizedLight),
+			"dracula" => Ok(Self::Dracula),
+			"mono" => Ok(Self::Mono),
+			_ => Err(()),
+		}
+	}
+}
+
+/// Parses a colour, either as a hex code, a CSS named colour, or in the CSS
+/// `rgb()` or `hsl()` functional notations.
+///
+/// Out-of-range components of the functional notations are clamped, and hues
+/// wrap around.
+fn parse_colour(value: &str) -> Option<LinearRgbaColour> {
+	const OPAQUE_ALPHA_VALUE: f32 = 1.0;
+
+	let value = value.trim();
+	// Malformed function calls fall through to the hex code parsing, which
+	// rejects them
+	let srgb_colour = if let Some([red, green, blue]) = parse_function_arguments(value, "rgb") {
+		[
+			parse_rgb_component(red)?,
+			parse_rgb_component(green)?,
+			parse_rgb_component(blue)?,
+		]
+	} else if let Some([hue, saturation, lightness]) = parse_function_arguments(value, "hsl") {
+		hsl_to_srgb(
+			parse_hue(hue)?,
+			parse_percentage(saturation)?,
+			parse_percentage(lightness)?,
+		)
+	} else if let Some(named_colour) = parse_named_colour(value) {
+		return Some(named_colour);
+	} else {
+		return parse_colour_hex_code(value);
+	};
+
+	let [red, green, blue] = srgb_colour.map(srgb_to_linear_rgb_channel);
+
+	Some([red, green, blue, OPAQUE_ALPHA_VALUE])
+}
+
+/// Splits the arguments of a call to a function with the provided name, which
+/// are separated by commas or whitespace.
+///
+/// Returns `None` if the value isn't a well-formed call to the function with
+/// exactly three arguments.
+fn parse_function_arguments<'a>(value: &'a str, name: &str) -> Option<[&'a str; 3]> {
+	let arguments = value
+		.strip_prefix(name)?
+		.trim_start()
+		.strip_prefix('(')?
+		.strip_suffix(')')?
+		.split(|character: char| character == ',' || character.is_whitespace())
+		.filter(|argument| !argument.is_empty())
+		.collect::<Vec<_>>();
+
+	arguments.try_into().ok()
+}
+
+/// Parses a component of an `rgb()` colour, which is either a number from `0`
+/// to `255` or a percentage, into the range `0.0` to `1.0`.
+fn parse_rgb_component(value: &str) -> Option<f32> {
+	if value.ends_with('%') {
+		return parse_percentage(value);
+	}
+
+	let component = value
+		.parse::<f32>()
+		.ok()
+		.filter(|number| number.is_finite())?;
+
+	Some((component / f32::from(u8::MAX)).clamp(0.0, 1.0))
+}
+
+/// Parses a hue in degrees, with an optional `deg` unit, into the range `0.0`
+/// to `360.0`.
+fn parse_hue(value: &str) -> Option<f32> {
+	const DEGREES_PER_TURN: f32 = 360.0;
+
+	let hue = value
+		.strip_suffix("deg")
+		.unwrap_or(value)
+		.parse::<f32>()
+		.ok()
+		.filter(|number| number.is_finite())?;
+
+	Some(hue.rem_euclid(DEGREES_PER_TURN))
+}
+
+/// Parses a percentage, where the `%` is optional, into the range `0.0` to
+/// `1.0`.
+fn parse_percentage(value: &str) -> Option<f32> {
+	let percentage = value
+		.strip_suffix('%')
+		.unwrap_or(value)
+		.parse::<f32>()
+		.ok()
+		.filter(|number| number.is_finite())?;
+
+	Some((percentage / 100.0).clamp(0.0, 1.0))
+}
+
+/// Converts a colour from HSL to sRGB, with the hue in degrees and the other
+/// components from `0.0` to `1.0`.
+fn hsl_to_srgb(hue: f32, saturation: f32, lightness: f32) -> [f32; 3] {
+	const DEGREES_PER_SECTOR: f32 = 60.0;
+
+	let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+	let sector = hue / DEGREES_PER_SECTOR;
+	let secondary = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+	let (red, green, blue) = match sector as u32 {
+		0 => (chroma, secondary, 0.0),
+		1 => (secondary, chroma, 0.0),
+		2 => (0.0, chroma, secondary),
+		3 => (0.0, secondary, chroma),
+		4 => (secondary, 0.0, chroma),
+		_ => (chroma, 0.0, secondary),
+	};
+	let lightness_offset = lightness - chroma / 2.0;
+
+	[red, green, blue].map(|component| component + lightness_offset)
+}
+
+/// Looks up one of the standard CSS named colours, ignoring case.
+fn parse_named_colour(name: &str) -> Option<LinearRgbaColour> {
+	/// The CSS named colours, sorted by name so that they can be searched.
+	const NAMED_COLOURS: &[(&str, [u8; 3])] = &[
+		("aliceblue", [0xF0, 0xF8, 0xFF]),
+		("antiquewhite", [0xFA, 0xEB, 0xD7]),
+		("aqua", [0x00, 0xFF, 0xFF]),
+		("aquamarine", [0x7F, 0xFF, 0xD4]),
+		("azure", [0xF0, 0xFF, 0xFF]),
+		("beige", [0xF5, 0xF5, 0xDC]),
+		("bisque", [0xFF, 0xE4, 0xC4]),
+		("black", [0x00, 0x00, 0x00]),
+		("blanchedalmond", [0xFF, 0xEB, 0xCD]),
+		("blue", [0x00, 0x00, 0xFF]),
+		("blueviolet", [0x8A, 0x2B, 0xE2]),
+		("brown", [0xA5, 0x2A, 0x2A]),
+		("burlywood", [0xDE, 0xB8, 0x87]),
+		("cadetblue", [0x5F, 0x9E, 0xA0]),
+		("chartreuse", [0x7F, 0xFF, 0x00]),
+		("chocolate", [0xD2, 0x69, 0x1E]),
+		("coral", [0xFF, 0x7F, 0x50]),
+		("cornflowerblue", [0x64, 0x95, 0xED]),
+		("cornsilk", [0xFF, 0xF8, 0xDC]),
+		("crimson", [0xDC, 0x14, 0x3C]),
+		("cyan", [0x00, 0xFF, 0xFF]),
+		("darkblue", [0x00, 0x00, 0x8B]),
+		("darkcyan", [0x00, 0x8B, 0x8B]),
+		("darkgoldenrod", [0xB8, 0x86, 0x0B]),
+		("darkgray", [0xA9, 0xA9, 0xA9]),
+		("darkgreen", [0x00, 0x64, 0x00]),
+		("darkgrey", [0xA9, 0xA9, 0xA9]),
+		("darkkhaki", [0xBD, 0xB7, 0x6B]),
+		("darkmagenta", [0x8B, 0x00, 0x8B]),
+		("darkolivegreen", [0x55, 0x6B, 0x2F]),
+		("darkorange", [0xFF, 0x8C, 0x00]),
+		("darkorchid", [0x99, 0x32, 0xCC]),
+		("darkred", [0x8B, 0x00, 0x00]),
+		("darksalmon", [0xE9, 0x96, 0x7A]),
+		("darkseagreen", [0x8F, 0xBC, 0x8F]),
+		("darkslateblue", [0x48, 0x3D, 0x8B]),
+		("darkslategray", [0x2F, 0x4F, 0x4F]),
+		("darkslategrey", [0x2F, 0x4F, 0x4F]),
+		("darkturquoise", [0x00, 0xCE, 0xD1]),
+		("darkviolet", [0x94, 0x00, 0xD3]),
+		("deeppink", [0xFF, 0x14, 0x93]),
+		("deepskyblue", [0x00, 0xBF, 0xFF]),
+		("dimgray", [0x69, 0x69, 0x69]),
+		("dimgrey", [0x69, 0x69, 0x69]),
+		("dodgerblue", [0x1E, 0x90, 0xFF]),
+		("firebrick", [0xB2, 0x22, 0x22]),
+		("floralwhite", [0xFF, 0xFA, 0xF0]),
+		("forestgreen", [0x22, 0x8B, 0x22]),
+		("fuchsia", [0xFF, 0x00, 0xFF]),
+		("gainsboro", [0xDC, 0xDC, 0xDC]),
+		("ghostwhite", [0xF8, 0xF8, 0xFF]),
+		("gold", [0xFF, 0xD7, 0x00]),
+		("goldenrod", [0xDA, 0xA5, 0x20]),
+		("gray", [0x80, 0x80, 0x80]),
+		("green", [0x00, 0x80, 0x00]),
+		("greenyellow", [0xAD, 0xFF, 0x2F]),
+		("grey", [0x80, 0x80, 0x80]),
+		("honeydew", [0xF0, 0xFF, 0xF0]),
+		("hotpink", [0xFF, 0x69, 0xB4]),
+		("indianred", [0xCD, 0x5C, 0x5C]),
+		("indigo", [0x4B, 0x00, 0x82]),
+		("ivory", [0xFF, 0xFF, 0xF0]),
+		("khaki", [0xF0, 0xE6, 0x8C]),
+		("lavender", [0xE6, 0xE6, 0xFA]),
+		("lavenderblush", [0xFF, 0xF0, 0xF5]),
+		("lawngreen", [0x7C, 0xFC, 0x00]),
+		("lemonchiffon", [0xFF, 0xFA, 0xCD]),
+		("lightblue", [0xAD, 0xD8, 0xE6]),
+		("lightcoral", [0xF0, 0x80, 0x80]),
+		("lightcyan", [0xE0, 0xFF, 0xFF]),
+		("lightgoldenrodyellow", [0xFA, 0xFA, 0xD2]),
+		("lightgray", [0xD3, 0xD3, 0xD3]),
+		("lightgreen", [0x90, 0xEE, 0x90]),
+		("lightgrey", [0xD3, 0xD3, 0xD3]),
+		("lightpink", [0xFF, 0xB6, 0xC1]),
+		("lightsalmon", [0xFF, 0xA0, 0x7A]),
+		("lightseagreen", [0x20, 0xB2, 0xAA]),
+		("lightskyblue", [0x87, 0xCE, 0xFA]),
+		("lightslategray", [0x77, 0x88, 0x99]),
+		("lightslategrey", [0x77, 0x88, 0x99]),
+		("lightsteelblue", [0xB0, 0xC4, 0xDE]),
+		("lightyellow", [0xFF, 0xFF, 0xE0]),
+		("lime", [0x00, 0xFF, 0x00]),
+		("limegreen", [0x32, 0xCD, 0x32]),
+		("linen", [0xFA, 0xF0, 0xE6]),
+		("magenta", [0xFF, 0x00, 0xFF]),
+		("maroon", [0x80, 0x00, 0x00]),
+		("mediumaquamarine", [0x66, 0xCD, 0xAA]),
+		("mediumblue", [0x00, 0x00, 0xCD]),
+		("mediumorchid", [0xBA, 0x55, 0xD3]),
+		("mediumpurple", [0x93, 0x70, 0xDB]),
+		("mediumseagreen", [0x3C, 0xB3, 0x71]),
+		("mediumslateblue", [0x7B, 0x68, 0xEE]),
+		("mediumspringgreen", [0x00, 0xFA, 0x9A]),
+		("mediumturquoise", [0x48, 0xD1, 0xCC]),
+		("mediumvioletred", [0xC7, 0x15, 0x85]),
+		("midnightblue", [0x19, 0x19, 0x70]),
+		("mintcream", [0xF5, 0xFF, 0xFA]),
+		("mistyrose", [0xFF, 0xE4, 0xE1]),
+		("moccasin", [0xFF, 0xE4, 0xB5]),
+		("navajowhite", [0xFF, 0xDE, 0xAD]),
+		("navy", [0x00, 0x00, 0x80]),
+		("oldlace", [0xFD, 0xF5, 0xE6]),
+		("olive", [0x80, 0x80, 0x00]),
+		("olivedrab", [0x6B, 0x8E, 0x23]),
+		("orange", [0xFF, 0xA5, 0x00]),
+		("orangered", [0xFF, 0x45, 0x00]),
+		("orchid", [0xDA, 0x70, 0xD6]),
+		("palegoldenrod", [0xEE, 0xE8, 0xAA]),
+		("palegreen", [0x98, 0xFB, 0x98]),
+		("paleturquoise", [0xAF, 0xEE, 0xEE]),
+		("palevioletred", [0xDB, 0x70, 0x93]),
+		("papayawhip", [0xFF, 0xEF, 0xD5]),
+		("peachpuff", [0xFF, 0xDA, 0xB9]),
+		("peru", [0xCD, 0x85, 0x3F]),
+		("pink", [0xFF, 0xC0, 0xCB]),
+		("plum", [0xDD, 0xA0, 0xDD]),
+		("powderblue", [0xB0, 0xE0, 0xE6]),
+		("purple", [0x80, 0x00, 0x80]),
+		("rebeccapurple", [0x66, 0x33, 0x99]),
+		("red", [0xFF, 0x00, 0x00]),
+		("rosybrown", [0xBC, 0x8F, 0x8F]),
+		("royalblue", [0x41, 0x69, 0xE1]),
+		("saddlebrown", [0x8B, 0x45, 0x13]),
+		("salmon", [0xFA, 0x80, 0x72]),
+		("sandybrown", [0xF4, 0xA4, 0x60]),
+		("seagreen", [0x2E, 0x8B, 0x57]),
+		("seashell", [0xFF, 0xF5, 0xEE]),
+		("sienna", [0xA0, 0x52, 0x2D]),
+		("silver", [0xC0, 0xC0, 0xC0]),
+		("skyblue", [0x87, 0xCE, 0xEB]),
+		("slateblue", [0x6A, 0x5A, 0xCD]),
+		("slategray", [0x70, 0x80, 0x90]),
+		("slategrey", [0x70, 0x80, 0x90]),
+		("snow", [0xFF, 0xFA, 0xFA]),
+		("springgreen", [0x00, 0xFF, 0x7F]),
+		("steelblue", [0x46, 0x82, 0xB4]),
+		("tan", [0xD2, 0xB4, 0x8C]),
+		("teal", [0x00, 0x80, 0x80]),
+		("thistle", [0xD8, 0xBF, 0xD8]),
+		("tomato", [0xFF, 0x63, 0x47]),
+		("turquoise", [0x40, 0xE0, 0xD0]),
+		("violet", [0xEE, 0x82, 0xEE]),
+		("wheat", [0xF5, 0xDE, 0xB3]),
+		("white", [0xFF, 0xFF, 0xFF]),
+		("whitesmoke", [0xF5, 0xF5, 0xF5]),
+		("yellow", [0xFF, 0xFF, 0x00]),
+		("yellowgreen", [0x9A, 0xCD, 0x32]),
+	];
+
+	let name = name.to_ascii_lowercase();
+	let index = NAMED_COLOURS
+		.binary_search_by(|(colour_name, _)| colour_name.cmp(&name.as_str()))
+		.ok()?;
+
+	Some(srgb_to_linear_rgba_colour(NAMED_COLOURS[index].1))
+}
+
+fn parse_colour_hex_code(mut hex_value: &str) -> Option<LinearRgbaColour> {
+	const HEX_CODE_MARKER: char = '#';
+	const HEX_RADIX: u32 = 0x10;
+	const FULL_LENGTH: usize = 3 * 2;
+	const FULL_LENGTH_WITH_ALPHA: usize = 4 * 2;
+	const SHORTHAND_LENGTH: usize = 3;
+	/// Shorthand digits stand for themselves repeated, such as `f` for `ff`.
+	const SHORTHAND_MULTIPLIER: u8 = 0x11;
+	const OPAQUE_ALPHA_VALUE: f32 = 1.0;
+
+	/// Parses a channel to a value from `0.0` to `1.0`, without converting it
+	/// to linear RGB.
+	fn parse_channel_value(channel_hex_value: &str) -> Option<f32> {
+		let mut parsed_value = u8::from_str_radix(channel_hex_value, HEX_RADIX).ok()?;
+		if channel_hex_value.len() == 1 {
+			parsed_value *= SHORTHAND_MULTIPLIER;
+		}
+
+		Some(f32::from(parsed_value) / f32::from(u8::MAX))
+	}
+
+	fn parse_single_channel(channel_hex_value: &str) -> Option<f32> {
+		let srgb_value = parse_channel_value(channel_hex_value)?;
+		let linear_rgb_value = srgb_to_linear_rgb_channel(srgb_value);
+
+		Some(linear_rgb_value)
+	}
+
+	// Remove the leading marker character if present
 	if hex_value.starts_with(HEX_CODE_MARKER) {
 		hex_value = hex_value
 			.strip_prefix(HEX_CODE_MARKER)
 			.expect("the string starts with the prefix");
 	}
 
-	// Trim trailing whitespace
-	hex_value = hex_value.trim_end();
+	// Trim trailing whitespace
+	hex_value = hex_value.trim_end();
+
+	// Ensure the value is of a valid length, which determines how many digits
+	// each channel has
+	// Non-ASCII characters are rejected here so that the channels can't be split in
+	// the middle of a character
+	if !hex_value.is_ascii() {
+		return None;
+	}
+	let channel_length = match hex_value.len() {
+		FULL_LENGTH | FULL_LENGTH_WITH_ALPHA => 2,
+		SHORTHAND_LENGTH => 1,
+		_ => return None,
+	};
+
+	// Parse the channels
+	// Alpha is already linear, so it isn't converted like the others
+	let alpha_value = match hex_value.get(3 * channel_length..) {
+		Some("") | None => OPAQUE_ALPHA_VALUE,
+		Some(alpha_hex_value) => parse_channel_value(alpha_hex_value)?,
+	};
+	Some([
+		parse_single_channel(&hex_value[0..channel_length])?,
+		parse_single_channel(&hex_value[channel_length..2 * channel_length])?,
+		parse_single_channel(&hex_value[2 * channel_length..3 * channel_length])?,
+		alpha_value,
+	])
+}
+
+/// Checks whether a slide file path refers to a video, based on its extension.
+fn is_video_path(file_path: &str) -> bool {
+	Path::new(file_path)
+		.extension()
+		.and_then(|extension| extension.to_str())
+		.is_some_and(|extension| {
+			VIDEO_FILE_EXTENSIONS
+				.iter()
+				.any(|video_extension| extension.eq_ignore_ascii_case(video_extension))
+		})
+}
+
+/// Resolves the paths of the images and videos of some slides relative to a
+/// base path, expanding any that refer to multiple files.
+fn resolve_slide_media_paths(
+	mut slides: Vec<Slide>,
+	base_path: &Path,
+) -> Result<Vec<Slide>, ParseError> {
+	for slide in &mut slides {
+		if let Some(background_image) = &mut slide.options.background_image {
+			*background_image = base_path
+				.join(&background_image)
+				.to_string_lossy()
+				.into_owned();
+		}
+		match &mut slide.content {
+			SlideContent::Image(file_path) | SlideContent::Video(file_path) => {
+				*file_path = base_path.join(&file_path).to_string_lossy().into_owned();
+			}
+			SlideContent::Images(file_paths) => {
+				// Images in a grid that refer to multiple files are expanded within the grid
+				let mut resolved_file_paths = Vec::with_capacity(file_paths.len());
+				for file_path in take(file_paths) {
+					let file_path = base_path.join(file_path);
+					match expand_image_path(&file_path)? {
+						Some(image_paths) => resolved_file_paths.extend(image_paths),
+						None => resolved_file_paths.push(file_path.to_string_lossy().into_owned()),
+					}
+				}
+				*file_paths = resolved_file_paths;
+			}
+			SlideContent::Text(_) | SlideContent::Empty => {}
+		}
+	}
+
+	// Expand image slides that refer to multiple files into one slide per file
+	let mut resolved_slides = Vec::with_capacity(slides.len());
+	for slide in slides {
+		let SlideContent::Image(file_path) = &slide.content else {
+			resolved_slides.push(slide);
+			continue;
+		};
+		let Some(image_paths) = expand_image_path(Path::new(file_path))? else {
+			resolved_slides.push(slide);
+			continue;
+		};
+
+		// Only the first of the expanded slides keeps the anchor
+		resolved_slides.extend(image_paths.into_iter().enumerate().map(
+			|(image_index, image_path)| Slide {
+				content: SlideContent::Image(image_path),
+				options: slide.options.clone(),
+				anchor:  slide.anchor.clone().filter(|_| image_index == 0),
+				caption: slide.caption.clone(),
+			},
+		));
+	}
+
+	Ok(resolved_slides)
+}
+
+/// Expands an image path that refers to multiple files, either through
+/// wildcards in its file name or by being a directory, into the sorted list of
+/// files it refers to.
+///
+/// Wildcards are only supported in the file name, where `*` matches any number
+/// of characters and `?` matches exactly one. A directory expands to every
+/// image file directly inside it.
+///
+/// Returns `None` if the path refers to a single file.
+fn expand_image_path(path: &Path) -> Result<Option<Vec<String>>, ParseError> {
+	let (directory, pattern) = if path.is_dir() {
+		(path, None)
+	} else {
+		let Some(file_name) = path.file_name().and_then(OsStr::to_str) else {
+			return Ok(None);
+		};
+		if !file_name.contains(WILDCARD_CHARS) {
+			return Ok(None);
+		}
+
+		(
+			path.parent().unwrap_or_else(|| Path::new("")),
+			Some(file_name),
+		)
+	};
+
+	// An empty directory refers to the current one, but it's kept empty when
+	// building the paths so that they match how they were written
+	let directory_to_read = if directory.as_os_str().is_empty() {
+		Path::new(".")
+	} else {
+		directory
+	};
+	let directory_entries =
+		read_dir(directory_to_read).map_err(|error| ParseError::UnreadableImageDirectory {
+			path: directory_to_read.to_owned(),
+			error,
+		})?;
+
+	let mut image_paths = directory_entries
+		.filter_map(Result::ok)
+		.filter(|entry| entry.path().is_file())
+		.map(|entry| directory.join(entry.file_name()))
+		.filter(|entry_path| match pattern {
+			Some(pattern) => entry_path
+				.file_name()
+				.and_then(OsStr::to_str)
+				.is_some_and(|file_name| matches_wildcard_pattern(pattern, file_name)),
+			None => ImageFormat::from_path(entry_path).is_ok(),
+		})
+		.map(|entry_path| entry_path.to_string_lossy().into_owned())
+		.collect::<Vec<_>>();
+	if image_paths.is_empty() {
+		return Err(ParseError::NoImagesFound {
+			path: path.to_owned(),
+		});
+	}
+	image_paths.sort();
+
+	Ok(Some(image_paths))
+}
+
+/// Checks whether a value matches a pattern, where `*` matches any number of
+/// characters and `?` matches exactly one.
+fn matches_wildcard_pattern(pattern: &str, value: &str) -> bool {
+	let pattern = pattern.chars().collect::<Vec<_>>();
+	let value = value.chars().collect::<Vec<_>>();
+
+	let (mut pattern_index, mut value_index) = (0, 0);
+	// The positions just after the most recent `*` and where it stopped matching,
+	// so that it can be made to match one more character if the rest fails
+	let mut backtrack_indices = None;
+	while value_index < value.len() {
+		match pattern.get(pattern_index) {
+			Some('*') => {
+				pattern_index += 1;
+				backtrack_indices = Some((pattern_index, value_index));
+			}
+			Some(&pattern_char) if pattern_char == '?' || pattern_char == value[value_index] => {
+				pattern_index += 1;
+				value_index += 1;
+			}
+			_ => {
+				let Some((backtrack_pattern_index, backtrack_value_index)) = backtrack_indices
+				else {
+					return false;
+				};
+				pattern_index = backtrack_pattern_index;
+				value_index = backtrack_value_index + 1;
+				backtrack_indices = Some((backtrack_pattern_index, value_index));
+			}
+		}
+	}
+
+	pattern[pattern_index..]
+		.iter()
+		.all(|&pattern_char| pattern_char == '*')
+}
+
+/// Truncates based on Unicode char boundaries instead of bytes.
+///
+/// This avoids potential panics when using the base [`truncate`] function.
+///
+/// Returns whether anything was actually truncated.
+///
+/// [`truncate`]: String::truncate
+fn char_truncate(string: &mut String, maximum_chars: usize) -> bool {
+	if let Some((index, _)) = string.char_indices().nth(maximum_chars) {
+		string.truncate(index);
+
+		return true;
+	}
+
+	false
+}
+
+/// Converts an opaque sRGB colour to linear RGBA.
+fn srgb_to_linear_rgba_colour(srgb_colour: [u8; 3]) -> LinearRgbaColour {
+	const OPAQUE_ALPHA_VALUE: f32 = 1.0;
+
+	let [red, green, blue] = srgb_colour
+		.map(|channel| srgb_to_linear_rgb_channel(f32::from(channel) / f32::from(u8::MAX)));
+
+	[red, green, blue, OPAQUE_ALPHA_VALUE]
+}
+
+/// Converts an sRGB value to linear RGB.
+///
+/// This implementation matches what is specified here: https://registry.khronos.org/OpenGL/extensions/EXT/EXT_texture_sRGB_decode.txt
+fn srgb_to_linear_rgb_channel(srgb_value: f32) -> f32 {
+	const GAMMA: f32 = 2.4;
+	const A: f32 = 0.055;
+	const X: f32 = 0.04045;
+	const PHI: f32 = 12.92;
+
+	if srgb_value > X {
+		((srgb_value + A) / (1.0 + A)).powf(GAMMA)
+	} else {
+		srgb_value / PHI
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::{
+		env::temp_dir,
+		error::Error,
+		fs::{create_dir_all, remove_dir_all, write},
+		io::{self, ErrorKind},
+		path::PathBuf,
+		time::Duration,
+	};
+
+	use super::{
+		decode_text,
+		matches_wildcard_pattern,
+		parse_colour,
+		parse_colour_hex_code,
+		parse_countdown,
+		parse_manifest,
+		parse_seconds,
+		split_colour_spans,
+		Anchor,
+		FitMode,
+		FitOverflow,
+		ForegroundMode,
+		HorizontalAlignment,
+		ManifestEntry,
+		Mirror,
+		Output,
+		ParseError,
+		Presentation,
+		Slide,
+		SlideContent,
+		SlideOptions,
+		SlideType,
+		TextRendering,
+		TextSpan,
+		Theme,
+		ThemePreset,
+		VerticalAlignment,
+	};
+
+	#[test]
+	fn many_slides() {
+		let actual_result = Presentation::load(
+			r"
+This is a text slide.
+
+Text slide with multiple lines:
+- item 1
+- item 2
+- item 3
+
+Another text slide!
+
+\
+
+@image.png
+This text won't be shown unless captions are turned on, since this is an image slide
+
+@video.mp4
+
+Final slide
+",
+		)
+		.slides;
+
+		let expected_result: Vec<Slide> = vec![
+			SlideContent::Text(r"This is a text slide.".to_owned()).into(),
+			SlideContent::Text(
+				r"Text slide with multiple lines:
+- item 1
+- item 2
+- item 3"
+					.to_owned(),
+			)
+			.into(),
+			SlideContent::Text(r"Another text slide!".to_owned()).into(),
+			SlideContent::Empty.into(),
+			Slide {
+				content: SlideContent::Image("image.png".to_owned()),
+				options: SlideOptions::default(),
+				anchor:  None,
+				caption: Some(
+					r"This text won't be shown unless captions are turned on, since this is an image slide"
+						.to_owned(),
+				),
+			},
+			SlideContent::Video("video.mp4".to_owned()).into(),
+			SlideContent::Text(r"Final slide".to_owned()).into(),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn empty_slides() {
+		let actual_result = Presentation::load(
+			r"
+\
+
+#.empty
+
+#.valign:top
+#.empty
+
+#.empty
+This text won't be shown, since this is an empty slide
+
+Text before the directive
+#.empty
+",
+		)
+		.slides;
+
+		let expected_result: Vec<Slide> = vec![
+			SlideContent::Empty.into(),
+			SlideContent::Empty.into(),
+			Slide {
+				content: SlideContent::Empty,
+				options: SlideOptions {
+					vertical_alignment: Some(VerticalAlignment::Top),
+					..Default::default()
+				},
+				anchor:  None,
+				caption: None,
+			},
+			SlideContent::Empty.into(),
+			SlideContent::Text(r"Text before the directive".to_owned()).into(),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn image_anchors() {
+		let actual_result = Presentation::load(
+			r"
+@diagram.png@top-left
+
+@photo.jpg
+
+@email@example.png
+
+@pixel-art.png
+#.nearest-threshold:0
+
+@clip.mp4@bottom
+",
+		)
+		.slides;
+
+		let expected_result: Vec<Slide> = vec![
+			Slide {
+				content: SlideContent::Image("diagram.png".to_owned()),
+				options: SlideOptions {
+					image_anchor: Some(Anchor::TopLeft),
+					..Default::default()
+				},
+				anchor:  None,
+				caption: None,
+			},
+			SlideContent::Image("photo.jpg".to_owned()).into(),
+			SlideContent::Image("email@example.png".to_owned()).into(),
+			Slide {
+				content: SlideContent::Image("pixel-art.png".to_owned()),
+				options: SlideOptions {
+					nearest_threshold: Some(0.0),
+					..Default::default()
+				},
+				anchor:  None,
+				caption: None,
+			},
+			Slide {
+				content: SlideContent::Video("clip.mp4".to_owned()),
+				options: SlideOptions {
+					image_anchor: Some(Anchor::Bottom),
+					..Default::default()
+				},
+				anchor:  None,
+				caption: None,
+			},
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn comments() {
+		let actual_result = Presentation::load(
+			r"
+# Comment at the beginning of a text slide
+Text slide
+# Comment at the end of a text slide
+
+# Solitary comment
+
+Another text slide
+
+A text slide demonstrating that comments
+don't work unless they're at the beginning
+of the line: # Comment
+
+# Comment at the end of the file
+",
+		)
+		.slides;
+
+		let expected_result: Vec<Slide> = vec![
+			SlideContent::Text(r"Text slide".to_owned()).into(),
+			SlideContent::Text(r"Another text slide".to_owned()).into(),
+			SlideContent::Text(
+				r"A text slide demonstrating that comments
+don't work unless they're at the beginning
+of the line: # Comment"
+					.to_owned(),
+			)
+			.into(),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn configuration() {
+		let actual_result = Presentation::load(
+			r"
+#.font:Roboto
+#.font:Helvetica
+#.fg:#ffffff
+#.fg:#000000
+#.fg-mode:cycle
+#.bg:#000000
+#.stage-colour:#ffffff
+#.blank-colour:#333333
+#.theme-dark:#ffffff #000000
+#.theme-light:#000000 #ffffff
+#.fit:width
+#.fit-overflow:start
+#.animations:false
+#.mirror:horizontal
+#.opacity:0.5
+#.kiosk:true
+#.bullets:true
+#.idle-reset:60
+#.text-outline:#000000
+#.text-outline-width:3
+#.margin-x:0.05
+#.margin-y:0.4
+#.watermark:logo.png
+#.watermark-position:top-left
+#.watermark-opacity:0.25
+#.max-image-size:3840
+#.countdown:5:00
+#.text-rendering:sharp
+#.shuffle:true
+#.touch-split:0.1
+#.nav-sound:true
+#.slidenumbers:true
+#.progressbar:true
+#.placeholders:true
+#.loop:true
+#.include:intro.sent
+#.valign:top
+#.font-size:48
+#.title:Testing Configuration
+
+This is a presentation for testing the configuration parameters.
+",
+		);
+
+		let expected_result = Presentation {
+			title:                 Some("Testing Configuration".to_owned()),
+			font_list:             vec!["Roboto".to_owned(), "Helvetica".to_owned()],
+			foreground_colour:     Some([1.0, 1.0, 1.0, 1.0]),
+			foreground_palette:    vec![[1.0, 1.0, 1.0, 1.0], [0.0, 0.0, 0.0, 1.0]],
+			foreground_mode:       Some(ForegroundMode::Cycle),
+			background_colour:     Some([0.0, 0.0, 0.0, 1.0]),
+			stage_colour:          Some([1.0, 1.0, 1.0, 1.0]),
+			blank_colour:          Some([0.033_104_762, 0.033_104_762, 0.033_104_762, 1.0]),
+			dark_theme:            Some(Theme {
+				foreground_colour: [1.0, 1.0, 1.0, 1.0],
+				background_colour: [0.0, 0.0, 0.0, 1.0],
+			}),
+			light_theme:           Some(Theme {
+				foreground_colour: [0.0, 0.0, 0.0, 1.0],
+				background_colour: [1.0, 1.0, 1.0, 1.0],
+			}),
+			fit_mode:              Some(FitMode::Width),
+			fit_overflow:          Some(FitOverflow::Start),
+			animations:            Some(false),
+			mirror:                Some(Mirror::Horizontal),
+			opacity:               Some(0.5),
+			kiosk:                 Some(true),
+			bullets:               Some(true),
+			idle_reset:            Some(Duration::from_secs(60)),
+			text_outline_colour:   Some([0.0, 0.0, 0.0, 1.0]),
+			text_outline_width:    Some(3.0),
+			horizontal_margin:     Some(0.05),
+			vertical_margin:       Some(0.4),
+			watermark:             Some("logo.png".to_owned()),
+			watermark_position:    Some(Anchor::TopLeft),
+			watermark_opacity:     Some(0.25),
+			maximum_image_size:    Some(3840),
+			countdown:             Some(Duration::from_secs(300)),
+			text_rendering:        Some(TextRendering::Sharp),
+			shuffle:               Some(true),
+			touch_split:           Some(0.1),
+			navigation_sound:      Some(true),
+			show_slide_numbers:    Some(true),
+			show_progress_bar:     Some(true),
+			placeholders:          Some(true),
+			looping:               Some(true),
+			default_slide_options: SlideOptions {
+				vertical_alignment: Some(VerticalAlignment::Top),
+				font_size: Some(48.0),
+				..Default::default()
+			},
+			slides:                vec![SlideContent::Text(
+				"This is a presentation for testing the configuration parameters.".to_owned(),
+			)
+			.into()],
+		};
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn theme_presets() {
+		let actual_result = [
+			"mono".parse::<ThemePreset>().map(ThemePreset::to_theme),
+			"unknown".parse::<ThemePreset>().map(ThemePreset::to_theme),
+		];
+
+		let expected_result = [
+			Ok(Theme {
+				foreground_colour: [0.0, 0.0, 0.0, 1.0],
+				background_colour: [1.0, 1.0, 1.0, 1.0],
+			}),
+			Err(()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn get_title() {
+		let actual_result = Presentation::load(
+			r"
+First Slide
+
+A text slide with some content
+",
+		)
+		.try_get_title();
+
+		let expected_result = Some("First Slide".to_owned());
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn get_explicit_title() {
+		let actual_result = Presentation::load(
+			r"
+#.title:The Actual Title
+
+@logo.png
+
+First Slide
+",
+		)
+		.try_get_title();
+
+		let expected_result = Some("The Actual Title".to_owned());
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn summarise() {
+		let presentation = Presentation::load(
+			r"
+Welcome to a presentation
+with a very long first slide
+
+@logo.png
+
+@example.mp4
+
+#.empty
+",
+		);
+		let actual_result = presentation
+			.slides
+			.iter()
+			.map(|slide| slide.content.summarise())
+			.collect::<Vec<_>>();
+
+		let expected_result = vec![
+			"Text (\"Welcome to a presentation with a very l\u{2026}\")".to_owned(),
+			"Image (logo.png)".to_owned(),
+			"Video (example.mp4)".to_owned(),
+			"Empty".to_owned(),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn countdowns() {
+		let actual_result = [
+			parse_countdown("5:00"),
+			parse_countdown(" 0:30 "),
+			parse_countdown("0:00"),
+			parse_countdown("1:60"),
+			parse_countdown("307445734561825861:00"),
+			parse_countdown("999999999999999999999:00"),
+		];
+
+		let expected_result = [
+			Some(Duration::from_secs(300)),
+			Some(Duration::from_secs(30)),
+			None,
+			None,
+			None,
+			None,
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn seconds() {
+		let actual_result = [
+			parse_seconds("60"),
+			parse_seconds(" 0.5 "),
+			parse_seconds("0"),
+			parse_seconds("-1"),
+			parse_seconds("1e30"),
+			parse_seconds("inf"),
+			parse_seconds("soon"),
+		];
+
+		let expected_result = [
+			Some(Duration::from_secs(60)),
+			Some(Duration::from_millis(500)),
+			None,
+			None,
+			None,
+			None,
+			None,
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn hex_colours() {
+		let actual_result = [
+			parse_colour("#fff"),
+			parse_colour("#F80"),
+			parse_colour("0a0"),
+			parse_colour("#ffffff80"),
+			parse_colour("#000000FF"),
+			parse_colour("#ffff"),
+			parse_colour("#ff"),
+			parse_colour("#ggg"),
+			parse_colour("#ffffffgg"),
+		];
+
+		let expected_result = [
+			parse_colour("#ffffff"),
+			parse_colour("#ff8800"),
+			parse_colour("#00aa00"),
+			Some([1.0, 1.0, 1.0, 128.0 / 255.0]),
+			parse_colour("#000000"),
+			None,
+			None,
+			None,
+			None,
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn functional_colours() {
+		let actual_result = [
+			parse_colour("rgb(255, 0, 0)"),
+			parse_colour("rgb(300 -5 0)"),
+			parse_colour("rgb(100%, 50%, 0%)"),
+			parse_colour("hsl(30, 100%, 50%)"),
+			parse_colour("hsl(390deg, 150%, 50%)"),
+			parse_colour("hsl(0, 0%, 100%)"),
+			parse_colour("rgb(1, 2)"),
+			parse_colour("rgb(1, 2, 3"),
+			parse_colour("hsl(red, 100%, 50%)"),
+		];
+
+		let expected_result = [
+			parse_colour_hex_code("#ff0000"),
+			parse_colour_hex_code("#ff0000"),
+			parse_colour("rgb(255, 127.5, 0)"),
+			parse_colour("rgb(255, 127.5, 0)"),
+			parse_colour("rgb(255, 127.5, 0)"),
+			parse_colour_hex_code("#ffffff"),
+			None,
+			None,
+			None,
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn named_colours() {
+		let actual_result = [
+			parse_colour("white"),
+			parse_colour("CornflowerBlue"),
+			parse_colour("rebeccapurple"),
+			parse_colour("notacolour"),
+		];
+
+		let expected_result = [
+			parse_colour_hex_code("#ffffff"),
+			parse_colour_hex_code("#6495ed"),
+			parse_colour_hex_code("#663399"),
+			None,
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn colour_spans() {
+		let red = parse_colour_hex_code("#ff0000");
+		let actual_result = [
+			split_colour_spans("This is [color=#ff0000]important[/color]!"),
+			split_colour_spans("[color=#ff0000]Unmatched"),
+			split_colour_spans("[color=reddish]Invalid[/color]"),
+			split_colour_spans("[color=#ff0000][/color]"),
+		];
+
+		let expected_result = [
+			vec![
+				TextSpan {
+					text:   "This is ",
+					colour: None,
+				},
+				TextSpan {
+					text:   "important",
+					colour: red,
+				},
+				TextSpan {
+					text:   "!",
+					colour: None,
+				},
+			],
+			vec![TextSpan {
+				text:   "[color=#ff0000]Unmatched",
+				colour: None,
+			}],
+			vec![TextSpan {
+				text:   "[color=reddish]Invalid[/color]",
+				colour: None,
+			}],
+			vec![TextSpan {
+				text:   "",
+				colour: None,
+			}],
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn arbitrary_input() {
+		/// Fragments that exercise the markers, options, and multi-byte
+		/// characters, which are combined in every order to build the inputs.
+		const FRAGMENTS: &[&str] = &[
+			"@",
+			"#",
+			"#.",
+			"#.fg:",
+			"#.empty",
+			":",
+			"\\",
+			"\n",
+			"*",
+			"[color=",
+			"]",
+			"[/color]",
+			"a\u{e9}",
+			"aaa",
+			"\u{1f389}",
+			" ",
+		];
+
+		let mut inputs = vec![String::new()];
+		for _ in 0..3 {
+			inputs = inputs
+				.iter()
+				.flat_map(|input| {
+					FRAGMENTS
+						.iter()
+						.map(move |fragment| input.clone() + fragment)
+				})
+				.collect();
+
+			// Loading the presentation and summarising it should never panic
+			for input in &inputs {
+				let presentation = Presentation::load(input);
+				let _ = presentation.try_get_title();
+				for slide in &presentation.slides {
+					let _ = slide.content.summarise();
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn anchors() {
+		let presentation = Presentation::load(
+			r"
+#.anchor:intro
+Introduction
+
+#.anchor:unused
+
+A slide without its own anchor
+
+#.anchor:summary
+Summary
+",
+		);
+		let actual_result = [
+			presentation.find_anchor("intro"),
+			presentation.find_anchor("unused"),
+			presentation.find_anchor("summary"),
+			presentation.find_anchor("unknown"),
+		];
+
+		let expected_result = [Some(0), Some(1), Some(2), None];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn text_search() {
+		let presentation = Presentation::load(
+			r"
+Introduction
+
+@search.png
+
+A [color=#ff0000]red[/color] slide
+about searching
+
+Summary of the search
+",
+		);
+		let actual_result = [
+			presentation.find_text("SEARCH", 0, true),
+			presentation.find_text("search", 3, true),
+			presentation.find_text("search", 2, false),
+			presentation.find_text("red slide about", 0, true),
+			presentation.find_text("intro", 1, true),
+			presentation.find_text("image", 0, true),
+		];
+
+		let expected_result = [Some(2), Some(3), Some(2), Some(2), Some(0), None];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn output_filtering() {
+		let mut presentation = Presentation::load(
+			r"
+For everyone
+
+#.only:presenter
+Notes for the presenter
+
+#.only:audience
+For the audience
+
+#.only:export
+For the handout
+
+#.only:unknown
+Also for everyone
+",
+		);
+		presentation.retain_slides_for(Output::Audience);
+		let actual_result = presentation
+			.slides
+			.iter()
+			.map(|slide| slide.content.clone())
+			.collect::<Vec<_>>();
+
+		let expected_result = vec![
+			SlideContent::Text("For everyone".to_owned()),
+			SlideContent::Text("For the audience".to_owned()),
+			SlideContent::Text("Also for everyone".to_owned()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn output_filtering_everything() {
+		let mut presentation = Presentation::load(
+			r"
+#.only:presenter
+Notes for the presenter
 
-	// Ensure the value is of the correct length
-	if hex_value.len() != EXPECTED_LENGTH {
-		return None;
+#.only:export
+For the handout
+",
+		);
+		presentation.retain_slides_for(Output::Audience);
+		let actual_result = presentation.slides;
+
+		let expected_result: Vec<Slide> = vec![SlideContent::Empty.into()];
+
+		assert_eq!(expected_result, actual_result);
 	}
 
-	// Parse the channels
-	Some([
-		parse_single_channel(&hex_value[0..2])?,
-		parse_single_channel(&hex_value[2..4])?,
-		parse_single_channel(&hex_value[4..6])?,
-		OPAQUE_ALPHA_VALUE,
-	])
-}
+	#[test]
+	fn normalisation() {
+		let actual_result = [
+			Presentation::load("#.normalize:true\nCafe\u{301}").slides[0]
+				.content
+				.clone(),
+			Presentation::load("Cafe\u{301}").slides[0].content.clone(),
+		];
 
-/// Truncates based on Unicode char boundaries instead of bytes.
-///
-/// This avoids potential panics when using the base [`truncate`] function.
-///
-/// Returns whether anything was actually truncated.
-///
-/// [`truncate`]: String::truncate
-fn char_truncate(string: &mut String, maximum_chars: usize) -> bool {
-	if let Some((index, _)) = string.char_indices().nth(maximum_chars) {
-		string.truncate(index);
+		let expected_result = [
+			SlideContent::Text("Caf\u{e9}".to_owned()),
+			SlideContent::Text("Cafe\u{301}".to_owned()),
+		];
 
-		return true;
+		assert_eq!(expected_result, actual_result);
 	}
 
-	false
-}
+	#[test]
+	fn reveal_steps() {
+		let presentation = Presentation::load(
+			r"
+#.reveal:true
 
-/// Converts an sRGB value to linear RGB.
-///
-/// This implementation matches what is specified here: https://registry.khronos.org/OpenGL/extensions/EXT/EXT_texture_sRGB_decode.txt
-fn srgb_to_linear_rgb_channel(srgb_value: f32) -> f32 {
-	const GAMMA: f32 = 2.4;
-	const A: f32 = 0.055;
-	const X: f32 = 0.04045;
-	const PHI: f32 = 12.92;
+Agenda
+- First
+- Second
 
-	if srgb_value > X {
-		((srgb_value + A) / (1.0 + A)).powf(GAMMA)
-	} else {
-		srgb_value / PHI
+#.reveal:false
+Shown all at once
+- First
+
+#.max-lines:2
+Cut off
+- First
+- Second
+
+@image.png
+",
+		);
+		let actual_result = presentation
+			.slides
+			.iter()
+			.map(|slide| slide.reveal_step_count(&presentation.default_slide_options))
+			.collect::<Vec<_>>();
+
+		let expected_result = vec![3, 1, 2, 1];
+
+		assert_eq!(expected_result, actual_result);
 	}
-}
 
-#[cfg(test)]
-mod tests {
-	// Uses
-	use super::{Presentation, Slide};
+	#[test]
+	fn directory_path() {
+		let directory_path = temp_dir();
+
+		let actual_result =
+			Presentation::load_from_path(&directory_path).map_err(|error| error.to_string());
+
+		let expected_result = Err(ParseError::Directory {
+			path: directory_path.clone(),
+		}
+		.to_string());
+
+		assert_eq!(expected_result, actual_result);
+	}
 
 	#[test]
-	fn many_slides() {
+	fn image_grids() {
 		let actual_result = Presentation::load(
 			r"
-This is a text slide.
+@first.png
+@second.png@top
+@video.mp4
+@third.png
 
-Text slide with multiple lines:
-- item 1
-- item 2
-- item 3
+@single.png
+# A comment
+",
+		)
+		.slides;
 
-Another text slide!
+		let expected_result: Vec<Slide> = vec![
+			SlideContent::Images(vec![
+				"first.png".to_owned(),
+				"second.png".to_owned(),
+				"third.png".to_owned(),
+			])
+			.into(),
+			SlideContent::Image("single.png".to_owned()).into(),
+		];
 
-\
+		assert_eq!(expected_result, actual_result);
+	}
 
-@image.png
-This text won't be shown, since this is an image slide
+	#[test]
+	fn captions() {
+		let actual_result = Presentation::load(
+			r"
+@screenshot.png
+The new settings screen
+# A comment
+With more options
 
-Final slide
+@first.png
+@second.png
+Before and after
+
+@video.mp4
+Ignored text
+
+Text
+#.captions:true
 ",
 		)
 		.slides;
 
 		let expected_result = vec![
-			Slide::Text(r"This is a text slide.".to_owned()),
-			Slide::Text(
-				r"Text slide with multiple lines:
-- item 1
-- item 2
-- item 3"
-					.to_owned(),
-			),
-			Slide::Text(r"Another text slide!".to_owned()),
-			Slide::Empty,
-			Slide::Image("image.png".to_owned()),
-			Slide::Text(r"Final slide".to_owned()),
+			Slide {
+				content: SlideContent::Image("screenshot.png".to_owned()),
+				options: SlideOptions::default(),
+				anchor:  None,
+				caption: Some("The new settings screen\nWith more options".to_owned()),
+			},
+			Slide {
+				content: SlideContent::Images(vec![
+					"first.png".to_owned(),
+					"second.png".to_owned(),
+				]),
+				options: SlideOptions::default(),
+				anchor:  None,
+				caption: Some("Before and after".to_owned()),
+			},
+			SlideContent::Video("video.mp4".to_owned()).into(),
+			Slide {
+				content: SlideContent::Text("Text".to_owned()),
+				options: SlideOptions {
+					captions: Some(true),
+					..Default::default()
+				},
+				anchor:  None,
+				caption: None,
+			},
 		];
 
 		assert_eq!(expected_result, actual_result);
 	}
 
 	#[test]
-	fn comments() {
-		let actual_result = Presentation::load(
+	fn placeholders() {
+		let contents = r"
+Generated {date} at {time}
+
+Slide 2 of {slides}
+
+{unknown} {DATE}
+";
+		let mut presentation =
+			Presentation::load(format!("#.placeholders:true\n{contents}").as_str());
+		let mut disabled_presentation = Presentation::load(contents);
+		presentation.substitute_placeholders(1_792_089_045);
+		disabled_presentation.substitute_placeholders(1_792_089_045);
+		let actual_result = [presentation.slides, disabled_presentation.slides];
+
+		let expected_result: [Vec<Slide>; 2] = [
+			vec![
+				SlideContent::Text("Generated 2026-10-15 at 18:30".to_owned()).into(),
+				SlideContent::Text("Slide 2 of 3".to_owned()).into(),
+				SlideContent::Text("{unknown} {DATE}".to_owned()).into(),
+			],
+			vec![
+				SlideContent::Text("Generated {date} at {time}".to_owned()).into(),
+				SlideContent::Text("Slide 2 of {slides}".to_owned()).into(),
+				SlideContent::Text("{unknown} {DATE}".to_owned()).into(),
+			],
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn includes() {
+		let directory_path = temp_dir().join("breeze-test-includes");
+		create_dir_all(directory_path.join("shared")).unwrap();
+		write(
+			directory_path.join("talk.sent"),
+			"#.font:Roboto\n\nFirst\n\n#.include:shared/intro.sent\n\nLast",
+		)
+		.unwrap();
+		write(
+			directory_path.join("shared/intro.sent"),
+			"#.font:Helvetica\n#.valign:top\n\n@logo.png\n\nIntroduction",
+		)
+		.unwrap();
+
+		let presentation = Presentation::load_from_path(directory_path.join("talk.sent"))
+			.map_err(|error| error.to_string());
+		remove_dir_all(&directory_path).unwrap();
+		let actual_result = presentation.map(|presentation| {
+			(
+				presentation.font_list,
+				presentation
+					.slides
+					.into_iter()
+					.map(|slide| (slide.content, slide.options.vertical_alignment))
+					.collect::<Vec<_>>(),
+			)
+		});
+
+		let expected_result = Ok((
+			vec!["Roboto".to_owned(), "Helvetica".to_owned()],
+			vec![
+				(SlideContent::Text("First".to_owned()), None),
+				(
+					SlideContent::Image(
+						directory_path
+							.join("shared")
+							.join("logo.png")
+							.to_string_lossy()
+							.into_owned(),
+					),
+					Some(VerticalAlignment::Top),
+				),
+				(
+					SlideContent::Text("Introduction".to_owned()),
+					Some(VerticalAlignment::Top),
+				),
+				(SlideContent::Text("Last".to_owned()), None),
+			],
+		));
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn background_images() {
+		let directory_path = temp_dir().join("breeze-test-background-images");
+		create_dir_all(&directory_path).unwrap();
+		write(
+			directory_path.join("talk.sent"),
+			"#.bgimage:paper.png\n\n#.bgimage:photos/title.jpg\nA Title\n\nA slide",
+		)
+		.unwrap();
+
+		let presentation = Presentation::load_from_path(directory_path.join("talk.sent"))
+			.map_err(|error| error.to_string());
+		remove_dir_all(&directory_path).unwrap();
+		let actual_result = presentation.map(|presentation| {
+			presentation
+				.background_image_paths()
+				.cloned()
+				.collect::<Vec<_>>()
+		});
+
+		let expected_result = Ok(vec![
+			directory_path
+				.join("paper.png")
+				.to_string_lossy()
+				.into_owned(),
+			directory_path
+				.join("photos/title.jpg")
+				.to_string_lossy()
+				.into_owned(),
+		]);
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn only_includes() {
+		let actual_result = Presentation::load("#.include:intro.sent")
+			.slides
+			.into_iter()
+			.map(|slide| slide.content)
+			.collect::<Vec<_>>();
+
+		let expected_result = vec![SlideContent::Empty];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn include_errors() {
+		let directory_path = temp_dir().join("breeze-test-include-errors");
+		create_dir_all(&directory_path).unwrap();
+		write(directory_path.join("a.sent"), "A\n\n#.include:b.sent").unwrap();
+		write(directory_path.join("b.sent"), "B\n\n#.include:a.sent").unwrap();
+		write(directory_path.join("c.sent"), "C\n\n#.include:missing.sent").unwrap();
+
+		let actual_result = [
+			Presentation::load_from_path(directory_path.join("a.sent")),
+			Presentation::load_from_path(directory_path.join("c.sent")),
+		]
+		.map(|result| result.map(|_| ()).map_err(|error| error.to_string()));
+		remove_dir_all(&directory_path).unwrap();
+
+		let expected_result = [
+			Err(ParseError::InInclude {
+				error:          Box::new(ParseError::InInclude {
+					error:          Box::new(ParseError::IncludeCycle {
+						path: directory_path.join("a.sent"),
+					}),
+					including_path: directory_path.join("b.sent"),
+				}),
+				including_path: directory_path.join("a.sent"),
+			}
+			.to_string()),
+			Err(ParseError::InInclude {
+				error:          Box::new(ParseError::UnreadableFile {
+					path:  directory_path.join("missing.sent"),
+					error: io::Error::from(ErrorKind::NotFound),
+				}),
+				including_path: directory_path.join("c.sent"),
+			}
+			.to_string()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn error_sources() {
+		let missing_path = temp_dir().join("breeze-test-error-sources.sent");
+
+		let actual_result = [
+			Presentation::load_from_path(missing_path),
+			Presentation::load_from_reader([0xC3, 0x28].as_slice()),
+		]
+		.map(|result| {
+			result.err().and_then(|error| {
+				error
+					.source()?
+					.downcast_ref::<io::Error>()
+					.map(io::Error::kind)
+			})
+		});
+
+		let expected_result = [Some(ErrorKind::NotFound), Some(ErrorKind::InvalidData)];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn invalid_options() {
+		let actual_result = Presentation::check(
+			"#.fg:#ffffff\n#.bg:blurple\n\n#.kiosk:yes\n#.reveal:true\nA slide",
+		)
+		.iter()
+		.map(ToString::to_string)
+		.collect::<Vec<_>>();
+
+		let expected_result = vec![
+			ParseError::InvalidColour {
+				line:  2,
+				value: "blurple".to_owned(),
+			}
+			.to_string(),
+			ParseError::InvalidBool {
+				line:  4,
+				value: "yes".to_owned(),
+			}
+			.to_string(),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn error_messages() {
+		let actual_result = ParseError::InManifest {
+			error:         Box::new(ParseError::UnreadableFile {
+				path:  PathBuf::from("intro.sent"),
+				error: io::Error::from(ErrorKind::NotFound),
+			}),
+			line_number:   3,
+			manifest_path: PathBuf::from("order.txt"),
+		}
+		.to_string();
+
+		let expected_result = "unable to read the presentation file\n\"intro.sent\"!\n(from line \
+		                       3 of the manifest\n\"order.txt\")";
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn foreground_cycle() {
+		let mut presentation = Presentation::load(
 			r"
-# Comment at the beginning of a text slide
-Text slide
-# Comment at the end of a text slide
+#.fg:#ffffff
+#.fg:#000000
+#.fg-mode:cycle
 
-# Solitary comment
+First
 
-Another text slide
+@image.png
 
-A text slide demonstrating that comments
-don't work unless they're at the beginning
-of the line: # Comment
+Third
 
-# Comment at the end of the file
+#.slide-fg:#ff0000
+Fourth
 ",
-		)
-		.slides;
+		);
+		presentation.cycle_foreground_colours();
+		let actual_result = presentation
+			.slides
+			.iter()
+			.map(|slide| slide.options.foreground_colour)
+			.collect::<Vec<_>>();
 
 		let expected_result = vec![
-			Slide::Text(r"Text slide".to_owned()),
-			Slide::Text(r"Another text slide".to_owned()),
-			Slide::Text(
-				r"A text slide demonstrating that comments
-don't work unless they're at the beginning
-of the line: # Comment"
-					.to_owned(),
-			),
+			Some([1.0, 1.0, 1.0, 1.0]),
+			None,
+			Some([0.0, 0.0, 0.0, 1.0]),
+			Some([1.0, 0.0, 0.0, 1.0]),
 		];
 
 		assert_eq!(expected_result, actual_result);
 	}
 
 	#[test]
-	fn configuration() {
+	fn text_encodings() {
+		let text = "#.fg:#ffffff\n\nA slide \u{2014} with a dash";
+		let utf_16_le = [0xFF, 0xFE]
+			.into_iter()
+			.chain(text.encode_utf16().flat_map(u16::to_le_bytes))
+			.collect::<Vec<_>>();
+		let utf_8_with_bom = [0xEF, 0xBB, 0xBF]
+			.into_iter()
+			.chain(text.bytes())
+			.collect::<Vec<_>>();
+		let actual_result = [
+			decode_text(utf_16_le).ok(),
+			decode_text(utf_8_with_bom).ok(),
+			decode_text(text.as_bytes().to_vec()).ok(),
+			decode_text(vec![0xC3, 0x28]).ok(),
+		];
+
+		let expected_result = [
+			Some(text.to_owned()),
+			Some(text.to_owned()),
+			Some(text.to_owned()),
+			None,
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn manifest() {
+		let actual_result = parse_manifest(
+			r"# The opening talk
+intro.sent
+
+@break.png
+talks/closing.sent
+",
+		);
+
+		let expected_result = vec![
+			(2, ManifestEntry::File("intro.sent")),
+			(3, ManifestEntry::Break),
+			(4, ManifestEntry::Slide("@break.png")),
+			(5, ManifestEntry::File("talks/closing.sent")),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn slide_options() {
 		let actual_result = Presentation::load(
 			r"
+#.valign:bottom
+
+#.valign:top
+A slide at the top
+
+A slide that uses the default
+
+#.align:right
+A slide aligned to the right
+
+#.align:justify
+A slide with an invalid alignment
+
+@image.png
+#.valign:center
+#.font-size:0
+
+#.font-size:12.5
+#.slide-margin:0.05
+A slide with a fixed font size
+
+#.slide-type:scroll
+#.scroll-duration:30
+Credits
+
+#.slide-type:scroll
+#.scroll-duration:1e30
+Endless credits
+
+#.slide-type:title
+#.subtitle-scale:0.25
+#.slide-margin:0.2,0
+A Title
+A subtitle
+
+#.slide-margin:0.5
+A slide with an invalid margin
+
+#.scale:0.5
+A smaller slide
+
+#.scale:-1
+A slide with a clamped scale
+
+#.backup:true
+An appendix
+
+#.bgimage:photo.jpg
+#.slide-fg:#000000
+A slide over a photo
+
+#.advance:5s
+A slide that advances by itself
+
+#.advance:2.5
+A slide that advances sooner
+
+#.advance:0
+A slide with an invalid advance duration
+
+#.advance:1e30
+A slide with an advance duration that's too long
+",
+		);
+
+		let expected_result = Presentation {
+			default_slide_options: SlideOptions {
+				vertical_alignment: Some(VerticalAlignment::Bottom),
+				..Default::default()
+			},
+			slides: vec![
+				Slide {
+					content: SlideContent::Text(r"A slide at the top".to_owned()),
+					options: SlideOptions {
+						vertical_alignment: Some(VerticalAlignment::Top),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				SlideContent::Text(r"A slide that uses the default".to_owned()).into(),
+				Slide {
+					content: SlideContent::Text(r"A slide aligned to the right".to_owned()),
+					options: SlideOptions {
+						horizontal_alignment: Some(HorizontalAlignment::Right),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				SlideContent::Text(r"A slide with an invalid alignment".to_owned()).into(),
+				Slide {
+					content: SlideContent::Image("image.png".to_owned()),
+					options: SlideOptions {
+						vertical_alignment: Some(VerticalAlignment::Center),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				Slide {
+					content: SlideContent::Text(r"A slide with a fixed font size".to_owned()),
+					options: SlideOptions {
+						font_size: Some(12.5),
+						margins: Some((0.05, 0.05)),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				Slide {
+					content: SlideContent::Text(r"Credits".to_owned()),
+					options: SlideOptions {
+						slide_type: Some(SlideType::Scroll),
+						scroll_duration: Some(Duration::from_secs(30)),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				Slide {
+					content: SlideContent::Text(r"Endless credits".to_owned()),
+					options: SlideOptions {
+						slide_type: Some(SlideType::Scroll),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				Slide {
+					content: SlideContent::Text("A Title\nA subtitle".to_owned()),
+					options: SlideOptions {
+						slide_type: Some(SlideType::Title),
+						subtitle_scale: Some(0.25),
+						margins: Some((0.2, 0.0)),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				SlideContent::Text(r"A slide with an invalid margin".to_owned()).into(),
+				Slide {
+					content: SlideContent::Text(r"A smaller slide".to_owned()),
+					options: SlideOptions {
+						scale: Some(0.5),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				Slide {
+					content: SlideContent::Text(r"A slide with a clamped scale".to_owned()),
+					options: SlideOptions {
+						scale: Some(0.05),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				Slide {
+					content: SlideContent::Text(r"An appendix".to_owned()),
+					options: SlideOptions {
+						backup: Some(true),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				Slide {
+					content: SlideContent::Text(r"A slide over a photo".to_owned()),
+					options: SlideOptions {
+						foreground_colour: Some([0.0, 0.0, 0.0, 1.0]),
+						background_image: Some("photo.jpg".to_owned()),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				Slide {
+					content: SlideContent::Text(r"A slide that advances by itself".to_owned()),
+					options: SlideOptions {
+						advance: Some(Duration::from_secs(5)),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				Slide {
+					content: SlideContent::Text(r"A slide that advances sooner".to_owned()),
+					options: SlideOptions {
+						advance: Some(Duration::from_millis(2500)),
+						..Default::default()
+					},
+					anchor:  None,
+					caption: None,
+				},
+				SlideContent::Text(r"A slide with an invalid advance duration".to_owned()).into(),
+				SlideContent::Text(r"A slide with an advance duration that's too long".to_owned())
+					.into(),
+			],
+			..Default::default()
+		};
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn append() {
+		let mut actual_result = Presentation::load(
+			r"
 #.font:Roboto
-#.font:Helvetica
 #.fg:#ffffff
-#.bg:#000000
 
-This is a presentation for testing the configuration parameters.
+First presentation
 ",
 		);
+		actual_result.append(Presentation::load(
+			r"
+#.font:Helvetica
+#.fg:#000000
+#.bg:#000000
+
+Second presentation
+",
+		));
 
 		let expected_result = Presentation {
-			font_list:         vec!["Roboto".to_owned(), "Helvetica".to_owned()],
+			font_list: vec!["Roboto".to_owned(), "Helvetica".to_owned()],
 			foreground_colour: Some([1.0, 1.0, 1.0, 1.0]),
+			foreground_palette: vec![[1.0, 1.0, 1.0, 1.0], [0.0, 0.0, 0.0, 1.0]],
 			background_colour: Some([0.0, 0.0, 0.0, 1.0]),
-			slides:            vec![Slide::Text(
-				"This is a presentation for testing the configuration parameters.".to_owned(),
-			)],
+			slides: vec![
+				SlideContent::Text(r"First presentation".to_owned()).into(),
+				SlideContent::Text(r"Second presentation".to_owned()).into(),
+			],
+			..Default::default()
 		};
 
 		assert_eq!(expected_result, actual_result);
 	}
 
 	#[test]
-	fn get_title() {
-		let actual_result = Presentation::load(
-			r"
-First Slide
-
-A text slide with some content
-",
-		)
-		.try_get_title();
+	fn wildcard_patterns() {
+		let actual_result = [
+			matches_wildcard_pattern("*.jpg", "photo.jpg"),
+			matches_wildcard_pattern("*.jpg", "photo.png"),
+			matches_wildcard_pattern("photo-?.jpg", "photo-1.jpg"),
+			matches_wildcard_pattern("photo-?.jpg", "photo-10.jpg"),
+			matches_wildcard_pattern("*-*.jpg", "a-b-c.jpg"),
+			matches_wildcard_pattern("*", ""),
+			matches_wildcard_pattern("a*b", "a"),
+		];
 
-		let expected_result = Some("First Slide".to_owned());
+		let expected_result = [true, false, true, false, true, true, false];
 
 		assert_eq!(expected_result, actual_result);
 	}
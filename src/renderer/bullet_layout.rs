@@ -0,0 +1,308 @@
+// Uses
+use gfx_glyph::{
+	ab_glyph::{Font, Rect, ScaleFont},
+	BuiltInLineBreaker,
+	GlyphPositioner,
+	Layout,
+	SectionGeometry,
+	SectionGlyph,
+	SectionText,
+};
+use glyph_brush::ToSectionText;
+
+// Constants
+/// The prefixes that make a line a bullet point.
+const BULLET_PREFIXES: &[&str] = &["- ", "* "];
+
+/// A piece of a line of text, which keeps track of where it came from in the
+/// original section.
+#[derive(Clone, Copy)]
+struct LinePiece<'a> {
+	section_index: usize,
+	byte_offset:   usize,
+	text:          SectionText<'a>,
+}
+
+/// Lays out text like [`Layout`], except that when bullet points wrap, the
+/// lines after the first are indented to align with the text after the
+/// bullet.
+///
+/// If there's a maximum number of lines, any lines past it are cut off,
+/// including ones that text has wrapped onto.
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct BulletLayout {
+	inner:           Layout<BuiltInLineBreaker>,
+	hanging_indents: bool,
+	maximum_lines:   Option<usize>,
+}
+
+impl BulletLayout {
+	pub fn new(
+		inner: Layout<BuiltInLineBreaker>,
+		hanging_indents: bool,
+		maximum_lines: Option<usize>,
+	) -> Self {
+		Self {
+			inner,
+			hanging_indents,
+			maximum_lines,
+		}
+	}
+
+	/// Lays out some line pieces with the inner layout, mapping the glyphs
+	/// back to their places in the original section.
+	fn layout_pieces<F>(
+		self,
+		fonts: &[F],
+		pieces: &[LinePiece],
+		geometry: SectionGeometry,
+	) -> Vec<SectionGlyph>
+	where
+		F: Font,
+	{
+		let texts = pieces.iter().map(|piece| piece.text).collect::<Vec<_>>();
+
+		let mut glyphs = self.inner.calculate_glyphs(fonts, &geometry, &texts);
+		for section_glyph in &mut glyphs {
+			let piece = pieces[section_glyph.section_index];
+			section_glyph.section_index = piece.section_index;
+			section_glyph.byte_index += piece.byte_offset;
+		}
+
+		glyphs
+	}
+}
+
+impl GlyphPositioner for BulletLayout {
+	fn calculate_glyphs<F, S>(
+		&self,
+		fonts: &[F],
+		geometry: &SectionGeometry,
+		sections: &[S],
+	) -> Vec<SectionGlyph>
+	where
+		F: Font,
+		S: ToSectionText,
+	{
+		if !self.hanging_indents && self.maximum_lines.is_none() {
+			return self.inner.calculate_glyphs(fonts, geometry, sections);
+		}
+
+		let section_texts = sections
+			.iter()
+			.map(ToSectionText::to_section_text)
+			.collect::<Vec<_>>();
+
+		// Each line is laid out on its own, so that bullet points can be laid out
+		// within narrower bounds
+		let (x, mut y) = geometry.screen_position;
+		let (width, height) = geometry.bounds;
+		let mut glyphs = Vec::new();
+		let mut line_height = 0.0;
+		let mut remaining_lines = self.maximum_lines.unwrap_or(usize::MAX);
+		for line in split_lines(&section_texts) {
+			if remaining_lines == 0 {
+				break;
+			}
+
+			line_height = line
+				.iter()
+				.map(|piece| {
+					let scaled_font = fonts[piece.text.font_id.0].as_scaled(piece.text.scale);
+
+					scaled_font.ascent() - scaled_font.descent() + scaled_font.line_gap()
+				})
+				.reduce(f32::max)
+				.unwrap_or(line_height);
+
+			let line_text = line.iter().map(|piece| piece.text.text).collect::<String>();
+			let bullet_prefix_length = BULLET_PREFIXES
+				.iter()
+				.find(|prefix| self.hanging_indents && line_text.starts_with(*prefix))
+				.map_or(0, |prefix| prefix.len());
+			let (bullet, content) = split_pieces_at(&line, bullet_prefix_length);
+
+			// The bullet is laid out on its own to find out how far to indent the content
+			let bullet_glyphs = self.layout_pieces(
+				fonts,
+				&bullet,
+				SectionGeometry {
+					screen_position: (x, y),
+					bounds:          (f32::INFINITY, height),
+				},
+			);
+			let indent = bullet_glyphs
+				.iter()
+				.map(|section_glyph| {
+					let scaled_font =
+						fonts[section_glyph.font_id.0].as_scaled(section_glyph.glyph.scale);
+
+					section_glyph.glyph.position.x + scaled_font.h_advance(section_glyph.glyph.id)
+						- x
+				})
+				.reduce(f32::max)
+				.unwrap_or(0.0);
+
+			let mut content_glyphs = self.layout_pieces(
+				fonts,
+				&content,
+				SectionGeometry {
+					screen_position: (x + indent, y),
+					bounds:          ((width - indent).max(0.0), height),
+				},
+			);
+
+			// The number of lines the content wrapped onto is worked out from the
+			// positions of its first and last glyphs
+			let baselines = content_glyphs
+				.iter()
+				.chain(&bullet_glyphs)
+				.map(|section_glyph| section_glyph.glyph.position.y);
+			let highest_baseline = baselines.clone().reduce(f32::min);
+			let wrapped_line_count = baselines.reduce(f32::max).zip(highest_baseline).map_or(
+				1.0,
+				|(lowest_baseline, highest_baseline)| {
+					((lowest_baseline - highest_baseline) / line_height).round() + 1.0
+				},
+			);
+
+			// Only as many of the wrapped lines as are left are kept
+			let wrapped_line_count = wrapped_line_count.min(remaining_lines as f32);
+			if let Some(highest_baseline) = highest_baseline {
+				let cut_off_baseline = highest_baseline + (wrapped_line_count - 0.5) * line_height;
+				content_glyphs
+					.retain(|section_glyph| section_glyph.glyph.position.y < cut_off_baseline);
+			}
+			remaining_lines -= wrapped_line_count as usize;
+
+			glyphs.extend(bullet_glyphs);
+			glyphs.extend(content_glyphs);
+			y += wrapped_line_count * line_height;
+		}
+
+		glyphs
+	}
+
+	fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect {
+		self.inner.bounds_rect(geometry)
+	}
+}
+
+/// Splits section texts into lines at every newline, with each line made up
+/// of the pieces of the texts on it.
+fn split_lines<'a>(section_texts: &[SectionText<'a>]) -> Vec<Vec<LinePiece<'a>>> {
+	let mut lines = vec![Vec::new()];
+
+	for (section_index, section_text) in section_texts.iter().enumerate() {
+		let mut byte_offset = 0;
+		for (line_index, line_text) in section_text.text.split('\n').enumerate() {
+			if line_index > 0 {
+				lines.push(Vec::new());
+			}
+			if !line_text.is_empty() {
+				lines
+					.last_mut()
+					.expect("there is always at least one line")
+					.push(LinePiece {
+						section_index,
+						byte_offset,
+						text: SectionText {
+							text: line_text,
+							..*section_text
+						},
+					});
+			}
+			byte_offset += line_text.len() + '\n'.len_utf8();
+		}
+	}
+
+	lines
+}
+
+/// Splits the pieces of a line in two at a byte index within the line.
+fn split_pieces_at<'a>(
+	pieces: &[LinePiece<'a>],
+	split_index: usize,
+) -> (Vec<LinePiece<'a>>, Vec<LinePiece<'a>>) {
+	let mut before = Vec::new();
+	let mut after = Vec::new();
+
+	let mut line_offset = 0;
+	for piece in pieces {
+		let piece_length = piece.text.text.len();
+		let local_split_index = split_index.saturating_sub(line_offset).min(piece_length);
+		let (before_text, after_text) = piece.text.text.split_at(local_split_index);
+		if !before_text.is_empty() {
+			before.push(LinePiece {
+				text: SectionText {
+					text: before_text,
+					..piece.text
+				},
+				..*piece
+			});
+		}
+		if !after_text.is_empty() {
+			after.push(LinePiece {
+				byte_offset: piece.byte_offset + local_split_index,
+				text: SectionText {
+					text: after_text,
+					..piece.text
+				},
+				..*piece
+			});
+		}
+		line_offset += piece_length;
+	}
+
+	(before, after)
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use gfx_glyph::SectionText;
+
+	use super::{split_lines, split_pieces_at};
+
+	#[test]
+	fn bullet_split() {
+		let section_texts = [
+			SectionText {
+				text: "- A bul",
+				..Default::default()
+			},
+			SectionText {
+				text: "let\n* Another",
+				..Default::default()
+			},
+		];
+		let lines = split_lines(&section_texts);
+		let (bullet, content) = split_pieces_at(&lines[1], 2);
+
+		let actual_result = (
+			lines
+				.iter()
+				.map(|line| {
+					line.iter()
+						.map(|piece| (piece.section_index, piece.byte_offset, piece.text.text))
+						.collect::<Vec<_>>()
+				})
+				.collect::<Vec<_>>(),
+			bullet
+				.iter()
+				.chain(&content)
+				.map(|piece| (piece.section_index, piece.byte_offset, piece.text.text))
+				.collect::<Vec<_>>(),
+		);
+
+		let expected_result = (
+			vec![
+				vec![(0, 0, "- A bul"), (1, 0, "let")],
+				vec![(1, 4, "* Another")],
+			],
+			vec![(1, 4, "* "), (1, 6, "Another")],
+		);
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
@@ -1,34 +1,46 @@
 // Modules
+mod bullet_layout;
 mod pipeline_option;
 
 // Uses
-use std::collections::HashMap;
+use std::{
+	collections::HashMap,
+	f32::consts::TAU,
+	num::NonZeroU32,
+	path::Path,
+	time::{Duration, Instant},
+};
 
 use anyhow::{anyhow, Context, Result as AnyhowResult};
 pub use gfx; // Required by `gfx_defines`
 use gfx::{
-	format::{Depth, Srgba8},
+	format::{ChannelType, Depth, Formatted, Srgba8, Swizzle},
 	gfx_defines,
 	gfx_impl_struct_meta,
 	gfx_pipeline,
 	gfx_pipeline_inner,
 	gfx_vertex_struct_meta,
-	texture::{AaMode, Kind, Mipmap},
+	memory::{Bind, Usage},
+	preset::blend,
+	state::ColorMask,
+	texture::{AaMode, ImageInfoCommon, Kind, Mipmap},
 	traits::FactoryExt,
+	BlendTarget,
 	Encoder,
+	Global,
 	PipelineState,
-	RenderTarget,
 	TextureSampler,
 	VertexBuffer,
 };
 use gfx_core::{
 	format::Vec4,
-	handle::{DepthStencilView, RenderTargetView, Sampler, ShaderResourceView},
+	handle::{DepthStencilView, RenderTargetView, Sampler, ShaderResourceView, Texture},
 	texture::{FilterMethod, SamplerInfo, WrapMode},
 	Device as DeviceTrait,
 	Factory as FactoryTrait,
 };
 use gfx_device_gl::{CommandBuffer, Device, Factory, Resources};
+use gfx_gl as gl;
 use gfx_glyph::{
 	ab_glyph::FontArc,
 	BuiltInLineBreaker,
@@ -43,32 +55,125 @@ use gfx_glyph::{
 };
 use glutin::{
 	context::PossiblyCurrentContext,
-	surface::{GlSurface, Surface, WindowSurface},
+	surface::{GlSurface, Surface, SwapInterval, WindowSurface},
 };
 use glutin_winit::GlWindow;
-use image::{DynamicImage, GenericImageView};
+use image::{imageops::flip_vertical_in_place, DynamicImage, GenericImageView, RgbaImage};
 use old_school_gfx_glutin_ext::{
 	resized_views,
 	window_builder as old_school_gfx_glutin_ext_window_builder,
 	Init,
 };
 use winit::{
-	dpi::PhysicalSize,
+	dpi::{PhysicalPosition, PhysicalSize},
 	event_loop::EventLoop,
 	window::{Window, WindowBuilder},
 };
 
-use self::pipeline_option::PipelineOption;
+use self::{bullet_layout::BulletLayout, pipeline_option::PipelineOption};
 use crate::{
-	presentation::Slide,
+	fonts::has_glyph,
+	presentation::{
+		split_colour_spans,
+		Anchor,
+		FitMode,
+		FitOverflow,
+		HorizontalAlignment,
+		Mirror,
+		Slide,
+		SlideContent,
+		SlideOptions,
+		SlideType,
+		TextRendering,
+		TextSpan,
+		VerticalAlignment,
+		ELLIPSIS,
+	},
+	video::{VideoInfo, VideoStream},
 	LinearRgbaColour,
 	IMAGE_SAMPLING_NEAREST_NEIGHBOUR_SCALING_FACTOR_MINIMUM,
-	USABLE_HEIGHT_PERCENTAGE,
-	USABLE_WIDTH_PERCENTAGE,
 };
 
+// Constants
+/// How long notifications stay on the screen.
+const NOTIFICATION_DURATION: Duration = Duration::from_secs(2);
+/// The font size of notifications, before accounting for the window's scale
+/// factor.
+const NOTIFICATION_FONT_SIZE: f32 = 24.0;
+/// The distance of notifications from the bottom of the screen, as a
+/// percentage of the screen height.
+const NOTIFICATION_MARGIN_PERCENTAGE: f32 = 0.04;
+/// The tint that leaves textures unchanged.
+const NO_TINT: LinearRgbaColour = [1.0; 4];
+/// The colour of text that takes up space without being seen, such as lines
+/// that haven't been revealed yet.
+const HIDDEN_COLOUR: LinearRgbaColour = [0.0; 4];
+/// The number of copies of text that are drawn around it to form an outline.
+const TEXT_OUTLINE_STEPS: u32 = 8;
+/// How long scrolling slides take to scroll past, if not specified.
+const DEFAULT_SCROLL_DURATION: Duration = Duration::from_secs(20);
+/// The size of the lines after the first on title slides, relative to the
+/// first, if not specified.
+const DEFAULT_SUBTITLE_SCALE: f32 = 0.5;
+/// How far the colour of the design guides is from the background colour
+/// towards the foreground colour.
+const GUIDE_COLOUR_STRENGTH: f32 = 0.25;
+/// The width of the design guide lines, before accounting for the window's
+/// scale factor.
+const GUIDE_LINE_WIDTH: f32 = 1.0;
+/// Used in place of an ellipsis when the font doesn't have one.
+const ASCII_ELLIPSIS: &str = "...";
+/// The largest the watermark can be, as percentages of the screen's width and
+/// height.
+const WATERMARK_MAXIMUM_PERCENTAGES: (f32, f32) = (0.15, 0.1);
+/// The distance of the watermark from the edges of the screen, as a
+/// percentage of the screen height.
+const WATERMARK_MARGIN_PERCENTAGE: f32 = 0.02;
+/// The font size of the slide number, before accounting for the window's
+/// scale factor.
+const SLIDE_NUMBER_FONT_SIZE: f32 = 20.0;
+/// The distance of the slide number from the edges of the screen, as a
+/// percentage of the screen height.
+const SLIDE_NUMBER_MARGIN_PERCENTAGE: f32 = 0.02;
+/// The height of the progress bar, before accounting for the window's scale
+/// factor.
+const PROGRESS_BAR_HEIGHT: f32 = 4.0;
+/// The gap between the images of a grid, as a percentage of the screen
+/// height.
+const IMAGE_GRID_GAP_PERCENTAGE: f32 = 0.02;
+/// The share of the usable height that the image of a captioned slide takes
+/// up, with the caption fitted to the rest.
+const CAPTIONED_IMAGE_HEIGHT_PERCENTAGE: f32 = 0.8;
+/// The gap between the image of a captioned slide and its caption, as a
+/// percentage of the screen height.
+const CAPTION_GAP_PERCENTAGE: f32 = 0.02;
+/// The colour the screen is dimmed with outside of the spotlight.
+const SPOTLIGHT_DIM_COLOUR: LinearRgbaColour = [0.0, 0.0, 0.0, 0.75];
+/// The radius of the spotlight, as a percentage of the screen height.
+const SPOTLIGHT_RADIUS_PERCENTAGE: f32 = 0.15;
+/// The width of the soft edge of the spotlight, before accounting for the
+/// window's scale factor.
+const SPOTLIGHT_EDGE_WIDTH: f32 = 4.0;
+/// The colour the screen is dimmed with behind the help overlay.
+const HELP_DIM_COLOUR: LinearRgbaColour = [0.0, 0.0, 0.0, 0.85];
+/// The colour of the help overlay's text, which is always light to stand out
+/// against the dimmed screen.
+const HELP_TEXT_COLOUR: LinearRgbaColour = [1.0; 4];
+/// The font size of the help overlay, before accounting for the window's
+/// scale factor.
+const HELP_FONT_SIZE: f32 = 24.0;
+/// The gap between the keys and their actions in the help overlay, before
+/// accounting for the window's scale factor.
+const HELP_COLUMN_GAP: f32 = 32.0;
+/// How far the size and sub-pixel position of a glyph can be from a cached
+/// one for it to be reused, when rendering text precisely.
+const PRECISE_TEXT_CACHE_TOLERANCE: f32 = 0.001;
+/// The time between frames of animations.
+const ANIMATION_FRAME_INTERVAL: Duration = Duration::from_micros(16_667);
+
 // Type Definitions
 type ColourFormat = Srgba8;
+type ColourSurface = <ColourFormat as Formatted>::Surface;
 type DepthFormat = Depth;
 
 gfx_defines! {
@@ -80,44 +185,158 @@ gfx_defines! {
 	pipeline image_pipeline {
 		vertex_buffer: PipelineOption<VertexBuffer<Vertex>> = (),
 		current_texture: PipelineOption<TextureSampler<LinearRgbaColour>> = "t_Current",
-		render_target: RenderTarget<ColourFormat> = "Target0",
+		tint: Global<LinearRgbaColour> = "u_Tint",
+		render_target: BlendTarget<ColourFormat> = ("Target0", ColorMask::all(), blend::REPLACE),
+	}
+
+	pipeline spotlight_pipeline {
+		vertex_buffer: VertexBuffer<Vertex> = (),
+		colour: Global<LinearRgbaColour> = "u_Colour",
+		screen_size: Global<[f32; 2]> = "u_ScreenSize",
+		centre: Global<[f32; 2]> = "u_Centre",
+		radius: Global<f32> = "u_Radius",
+		edge_width: Global<f32> = "u_EdgeWidth",
+		render_target: BlendTarget<ColourFormat> = ("Target0", ColorMask::all(), blend::ALPHA),
 	}
 }
 
+/// The presentation-wide settings that control how slides are rendered.
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct RenderSettings {
+	pub foreground_colour:     LinearRgbaColour,
+	pub background_colour:     LinearRgbaColour,
+	/// The colour of the screen outside of the usable space. If unset, the
+	/// whole screen uses the background colour.
+	pub stage_colour:          Option<LinearRgbaColour>,
+	/// The colour the whole screen is filled with when it's blanked. If unset,
+	/// the blanked screen uses the background and stage colours.
+	pub blank_colour:          Option<LinearRgbaColour>,
+	pub fit_mode:              FitMode,
+	pub fit_overflow:          FitOverflow,
+	/// Whether animations are enabled. When disabled, anything animated is
+	/// shown in its final state immediately.
+	///
+	/// This is the single switch that every animated element must respect.
+	pub animations_enabled:    bool,
+	pub mirror:                Option<Mirror>,
+	/// Multiplies the alpha of the text and of everything drawn over the
+	/// slides. The background isn't affected, since the window itself is always
+	/// opaque.
+	pub opacity:               f32,
+	/// The colour of the outline drawn around text, if any.
+	pub text_outline_colour:   Option<LinearRgbaColour>,
+	/// The width of the text outline, before accounting for the window's scale
+	/// factor.
+	pub text_outline_width:    f32,
+	/// Whether wrapped bullet points are indented to align with the text after
+	/// the bullet.
+	pub hanging_indents:       bool,
+	/// The fractions of the screen's width and height that slides are fit
+	/// within.
+	pub usable_percentages:    (f32, f32),
+	/// The path of an image drawn on top of every slide, which must be in the
+	/// image cache.
+	pub watermark:             Option<String>,
+	/// Where the watermark is placed on the screen.
+	pub watermark_position:    Anchor,
+	/// Multiplies the alpha of the watermark.
+	pub watermark_opacity:     f32,
+	/// Whether the slide number is shown in a corner of the screen.
+	pub show_slide_numbers:    bool,
+	/// Whether a bar along the bottom of the screen shows how far through the
+	/// presentation the current slide is.
+	pub show_progress_bar:     bool,
+	/// Whether drawing is synchronised with the display's refresh rate.
+	pub vsync:                 bool,
+	pub text_rendering:        TextRendering,
+	pub default_slide_options: SlideOptions,
+}
+
+#[allow(clippy::struct_excessive_bools)]
 pub struct Renderer<'a> {
 	// Window Management
 	window:         Window,
 	last_view_size: PhysicalSize<u32>,
 
 	// Rendering Infrastructure
-	gl_surface:     Surface<WindowSurface>,
-	gl_context:     PossiblyCurrentContext,
-	device:         Device,
-	factory:        Factory,
-	colour_view:    RenderTargetView<Resources, ColourFormat>,
-	depth_view:     DepthStencilView<Resources, DepthFormat>,
-	encoder:        Encoder<Resources, CommandBuffer>,
-	glyph_brush:    GlyphBrush<Resources, Factory, FontArc>,
-	image_pipeline: PipelineState<Resources, image_pipeline::Meta>,
+	gl_surface:             Surface<WindowSurface>,
+	gl_context:             PossiblyCurrentContext,
+	device:                 Device,
+	factory:                Factory,
+	colour_view:            RenderTargetView<Resources, ColourFormat>,
+	depth_view:             DepthStencilView<Resources, DepthFormat>,
+	encoder:                Encoder<Resources, CommandBuffer>,
+	glyph_brush:            GlyphBrush<Resources, Factory, FontArc>,
+	image_pipeline:         PipelineState<Resources, image_pipeline::Meta>,
+	/// The same as `image_pipeline`, but blended with what's already drawn
+	/// according to the alpha of the texture.
+	blended_image_pipeline: PipelineState<Resources, image_pipeline::Meta>,
+	spotlight_pipeline:     PipelineState<Resources, spotlight_pipeline::Meta>,
+
+	// Settings
+	settings: RenderSettings,
 
 	// Runtime State
-	foreground_colour:               LinearRgbaColour,
-	background_colour:               LinearRgbaColour,
 	image_sampler_nearest_neighbour: Sampler<Resources>,
-	image_sampler_anisotropic:       Sampler<Resources>,
-	image_texture_cache:             HashMap<&'a String, CachedImageTexture>,
-	image_pipeline_data:             image_pipeline::Data<Resources>,
+	image_sampler_anisotropic: Sampler<Resources>,
+	/// A single white pixel, for drawing solid-colour rects with the image
+	/// pipeline.
+	solid_texture: ShaderResourceView<Resources, Vec4<f32>>,
+	image_texture_cache: HashMap<&'a String, CachedImageTexture>,
+	image_pipeline_data: image_pipeline::Data<Resources>,
+	video_info_cache: HashMap<&'a String, VideoInfo>,
+	video_playback: Option<VideoPlayback>,
+	/// The video whose playback couldn't be started, so that it isn't retried
+	/// on every redraw while its slide is shown.
+	failed_video_path: Option<String>,
+	notification: Option<Notification>,
+	/// Whether presenter overlays, such as notifications, are drawn on top of
+	/// the slides.
+	overlays_visible: bool,
+	/// Whether the design guides, showing the usable space and the center of
+	/// the screen, are drawn on top of the slides.
+	guides_visible: bool,
+	/// Whether everything but a circle around the cursor is dimmed.
+	spotlight_enabled: bool,
+	/// The keybindings listed by the help overlay, and their actions, while
+	/// it's shown.
+	help: Option<Vec<(&'static str, &'static str)>>,
+	/// Whether the next frame should be captured once it's drawn.
+	screenshot_requested: bool,
+	/// The last captured frame, until it's taken.
+	screenshot: Option<RgbaImage>,
+	/// The last known position of the cursor within the window, if it has
+	/// moved since the window was opened.
+	cursor_position: Option<(f32, f32)>,
+	/// The texture slides are rendered to before being drawn flipped, when
+	/// mirroring.
+	mirror_target: Option<MirrorTarget>,
+	scroll_animation: Option<ScrollAnimation>,
+	/// The measurements of the last text slide that was laid out, so that
+	/// redrawing it doesn't need to measure it again.
+	text_layout_cache: Option<CachedTextLayout>,
+	/// What's put at the end of text that's cut off, which is an ellipsis
+	/// unless the font doesn't have one.
+	ellipsis: String,
 }
 
 impl<'a> Renderer<'a> {
+	/// Opens a window and prepares everything required to render slides to
+	/// it.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the window or any of the rendering resources can't
+	/// be created.
 	pub fn new<F>(
 		event_loop: &EventLoop<()>,
 		window_builder: WindowBuilder,
 		additional_window_configuration: F,
 		font: FontArc,
-		foreground_colour: LinearRgbaColour,
-		background_colour: LinearRgbaColour,
+		settings: RenderSettings,
 		image_cache: HashMap<&'a String, DynamicImage>,
+		video_info_cache: HashMap<&'a String, VideoInfo>,
 	) -> AnyhowResult<Self>
 	where
 		F: FnOnce(&Window),
@@ -143,9 +362,34 @@ impl<'a> Renderer<'a> {
 
 		additional_window_configuration(&window);
 
+		// Not every platform supports changing the swap interval, in which case
+		// its default is kept
+		let swap_interval = if settings.vsync {
+			SwapInterval::Wait(NonZeroU32::MIN)
+		} else {
+			SwapInterval::DontWait
+		};
+		let _ = gl_surface.set_swap_interval(&gl_context, swap_interval);
+
 		let encoder = factory.create_command_buffer().into();
 
-		let glyph_brush = GlyphBrushBuilder::using_font(font).build(factory.clone());
+		// Not every font has an ellipsis, so cut-off text ends with periods instead if
+		// the loaded one doesn't
+		let ellipsis = if has_glyph(&font, ELLIPSIS) {
+			ELLIPSIS.to_string()
+		} else {
+			ASCII_ELLIPSIS.to_owned()
+		};
+
+		let glyph_brush_builder = GlyphBrushBuilder::using_font(font);
+		let glyph_brush = match settings.text_rendering {
+			TextRendering::Smooth => glyph_brush_builder,
+			TextRendering::Precise => glyph_brush_builder
+				.draw_cache_scale_tolerance(PRECISE_TEXT_CACHE_TOLERANCE)
+				.draw_cache_position_tolerance(PRECISE_TEXT_CACHE_TOLERANCE),
+			TextRendering::Sharp => glyph_brush_builder.texture_filter_method(FilterMethod::Scale),
+		}
+		.build(factory.clone());
 
 		let image_pipeline = factory
 			.create_pipeline_simple(
@@ -154,9 +398,29 @@ impl<'a> Renderer<'a> {
 				image_pipeline::new(),
 			)
 			.with_context(|| "unable to prepare the rendering pipeline for texture rendering")?;
+		let blended_image_pipeline = factory
+			.create_pipeline_simple(
+				include_bytes!("./texture_simple.vert"),
+				include_bytes!("./texture_simple.frag"),
+				image_pipeline::Init {
+					render_target: ("Target0", ColorMask::all(), blend::ALPHA),
+					..image_pipeline::new()
+				},
+			)
+			.with_context(|| {
+				"unable to prepare the rendering pipeline for blended texture rendering"
+			})?;
+		let spotlight_pipeline = factory
+			.create_pipeline_simple(
+				include_bytes!("./texture_simple.vert"),
+				include_bytes!("./spotlight.frag"),
+				spotlight_pipeline::new(),
+			)
+			.with_context(|| "unable to prepare the rendering pipeline for the spotlight")?;
 		let image_pipeline_data = image_pipeline::Data {
 			vertex_buffer:   None,
 			current_texture: None,
+			tint:            NO_TINT,
 			render_target:   colour_view.clone(),
 		};
 
@@ -167,6 +431,14 @@ impl<'a> Renderer<'a> {
 		let image_sampler_nearest_neighbour =
 			factory.create_sampler(SamplerInfo::new(FilterMethod::Scale, WrapMode::Clamp));
 
+		let (_, solid_texture) = factory
+			.create_texture_immutable::<ColourFormat>(
+				Kind::D2(1, 1, AaMode::Single),
+				Mipmap::Provided,
+				&[&[[u8::MAX; 4]]],
+			)
+			.with_context(|| "unable to prepare the texture for solid colours")?;
+
 		let last_view_size = window.inner_size();
 
 		let image_texture_cache = convert_image_cache_to_textures(&mut factory, image_cache)
@@ -184,16 +456,81 @@ impl<'a> Renderer<'a> {
 			encoder,
 			glyph_brush,
 			image_pipeline,
-			foreground_colour,
-			background_colour,
+			blended_image_pipeline,
+			spotlight_pipeline,
+			settings,
 			image_sampler_nearest_neighbour,
 			image_sampler_anisotropic,
+			solid_texture,
 			image_texture_cache,
 			image_pipeline_data,
+			video_info_cache,
+			video_playback: None,
+			failed_video_path: None,
+			notification: None,
+			overlays_visible: true,
+			guides_visible: false,
+			spotlight_enabled: false,
+			help: None,
+			screenshot_requested: false,
+			screenshot: None,
+			cursor_position: None,
+			mirror_target: None,
+			scroll_animation: None,
+			text_layout_cache: None,
+			ellipsis,
 		})
 	}
 
-	pub fn render(&mut self, slide: &Slide) {
+	/// Renders a slide to the window.
+	///
+	/// # Panics
+	///
+	/// Panics if drawing to the window fails.
+	///
+	/// If `revealed_lines` is set, only that many lines of a text slide are
+	/// shown, with the rest still taking up space so that the text doesn't move
+	/// as more of it is revealed.
+	///
+	/// If `slide_position` is set, it's the position of the slide in the order
+	/// it's shown in and the number of slides in that order, which are shown by
+	/// the slide number and progress bar if they're enabled.
+	pub fn render(
+		&mut self,
+		slide: &Slide,
+		revealed_lines: Option<usize>,
+		slide_position: Option<(usize, usize)>,
+	) {
+		self.render_slide(slide, revealed_lines, slide_position, None);
+	}
+
+	/// Renders the blanked screen, which hides the current slide.
+	///
+	/// # Panics
+	///
+	/// Panics if drawing to the window fails.
+	pub fn render_blank(&mut self) {
+		self.render_slide(
+			&SlideContent::Empty.into(),
+			None,
+			None,
+			self.settings.blank_colour,
+		);
+	}
+
+	/// Renders a slide to the window as with [`render`].
+	///
+	/// If `screen_colour` is set, the whole screen is filled with it instead
+	/// of the background and stage colours, and without any background image.
+	///
+	/// [`render`]: Self::render
+	fn render_slide(
+		&mut self,
+		slide: &Slide,
+		revealed_lines: Option<usize>,
+		slide_position: Option<(usize, usize)>,
+		screen_colour: Option<LinearRgbaColour>,
+	) {
 		/// Doesn't really matter, but we need something to start with before
 		/// scaling to fit the space.
 		///
@@ -204,9 +541,18 @@ impl<'a> Renderer<'a> {
 		/// It doesn't seem like there's a way to fully disable wrapping in
 		/// `glyph-brush`.
 		const BASE_FONT_SIZE: f32 = 1.0;
+		/// The number of pixels in a typographic point, at the standard 96 DPI.
+		const PIXELS_PER_POINT: f32 = 96.0 / 72.0;
 
 		// Handle resizes
 		let window_size = self.window.inner_size();
+		// Minimised windows can report a size of zero, which the surface can't be
+		// resized to, so nothing is drawn until the window is restored.
+		// `last_view_size` is left as it was, so the views are only recreated on
+		// restore if the size actually changed
+		if !is_drawable_size(window_size) {
+			return;
+		}
 		if self.last_view_size != window_size {
 			self.window
 				.resize_surface(&self.gl_surface, &self.gl_context);
@@ -222,145 +568,1375 @@ impl<'a> Renderer<'a> {
 			self.last_view_size = window_size;
 		}
 
-		// Clear the screen with the background colour
-		self.encoder
-			.clear(&self.colour_view, self.background_colour);
-
 		let (screen_width, screen_height, ..) = self.colour_view.get_dimensions();
+
+		// When mirroring, the slide is rendered to a texture first so that it can then
+		// be drawn to the screen flipped
+		if self.settings.mirror.is_some()
+			&& self.mirror_target.as_ref().map_or(true, |mirror_target| {
+				mirror_target.dimensions != (screen_width, screen_height)
+			}) {
+			self.mirror_target =
+				MirrorTarget::new(&mut self.factory, (screen_width, screen_height));
+		}
+		let target_view = match (self.settings.mirror, &self.mirror_target) {
+			(Some(_), Some(mirror_target)) => mirror_target.render_target_view.clone(),
+			_ => self.colour_view.clone(),
+		};
+		self.image_pipeline_data.render_target = target_view.clone();
+
+		// Resolve the options for this slide
+		let mut slide_options = slide.options.clone();
+		slide_options.merge(&self.settings.default_slide_options);
+
+		// The margins are on both sides of the usable space
+		let usable_percentages = slide_options.margins.map_or(
+			self.settings.usable_percentages,
+			|(horizontal_margin, vertical_margin)| {
+				(1.0 - 2.0 * horizontal_margin, 1.0 - 2.0 * vertical_margin)
+			},
+		);
 		let (screen_width, screen_height) = (f32::from(screen_width), f32::from(screen_height));
 		let (usable_width, usable_height) = (
-			screen_width * USABLE_WIDTH_PERCENTAGE,
-			screen_height * USABLE_HEIGHT_PERCENTAGE,
+			screen_width * usable_percentages.0,
+			screen_height * usable_percentages.1,
+		);
+		let usable_rect = (
+			(screen_width - usable_width) / 2.0,
+			(screen_height - usable_height) / 2.0,
+			usable_width,
+			usable_height,
 		);
+
+		// The window is always opaque, so the opacity doesn't apply to the background
+		let background_colour = screen_colour.unwrap_or(self.settings.background_colour);
+		let foreground_colour =
+			with_opacity(self.settings.foreground_colour, self.settings.opacity);
+
+		// Clear the screen with the background colour
+		// If there's a stage colour, the background only covers the usable space,
+		// unless the whole screen is being filled with one colour
+		if let Some(stage_colour) = self
+			.settings
+			.stage_colour
+			.filter(|_| screen_colour.is_none())
+		{
+			self.encoder.clear(&target_view, stage_colour);
+			self.draw_rect(
+				background_colour,
+				screen_width,
+				screen_height,
+				(screen_width - usable_width) / 2.0,
+				(screen_height - usable_height) / 2.0,
+				usable_width,
+				usable_height,
+			);
+		} else {
+			self.encoder.clear(&target_view, background_colour);
+		}
+
+		// Draw the background image over the whole screen, behind the slide
+		// The screen is left without it if it's being filled with one colour, such as
+		// when it's blanked
+		let background_image = slide_options
+			.background_image
+			.as_ref()
+			.filter(|_| screen_colour.is_none())
+			.and_then(|background_image_path| self.image_texture_cache.get(background_image_path))
+			.map(|texture| (texture.dimensions, texture.resource_view.clone()));
+		if let Some((dimensions, resource_view)) = background_image {
+			self.draw_background_image(resource_view, dimensions, (screen_width, screen_height));
+		}
+
 		let base_scale = BASE_FONT_SIZE * self.window.scale_factor() as f32;
 
-		match slide {
-			Slide::Text(text) => {
-				/// Floating-point imprecision can cause text to
-				/// wrap when it's not supposed to because it's
-				/// ever-so-slightly larger than the bounds.
-				///
-				/// This value exists to account for that.
-				const FLOATING_POINT_IMPRECISION_ACCOMMODATION: f32 = 0.1;
+		// Stop any video playback or animation once its slide is no longer shown
+		if !matches!(slide.content, SlideContent::Video(_)) {
+			self.video_playback = None;
+			self.failed_video_path = None;
+		}
+		if !matches!(slide.content, SlideContent::Text(_)) {
+			self.scroll_animation = None;
+		}
+
+		// Captions are shown below the images of image slides, which are fitted to the
+		// space above them
+		let caption = slide.caption.as_deref().filter(|_| {
+			slide_options.captions.unwrap_or(false)
+				&& matches!(
+					slide.content,
+					SlideContent::Image(_) | SlideContent::Images(_)
+				)
+		});
+		let (image_rect, caption_rect) = if caption.is_some() {
+			split_caption_area(usable_rect, screen_height * CAPTION_GAP_PERCENTAGE)
+		} else {
+			(usable_rect, (0.0, 0.0, 0.0, 0.0))
+		};
+
+		match &slide.content {
+			SlideContent::Text(text) => {
 				const NON_CENTERED_LAYOUT: Layout<BuiltInLineBreaker> = Layout::Wrap {
 					line_breaker: BuiltInLineBreaker::UnicodeLineBreaker,
 					h_align:      HorizontalAlign::Left,
 					v_align:      VerticalAlign::Top,
 				};
 
+				let foreground_colour = slide_options
+					.foreground_colour
+					.map_or(foreground_colour, |colour| {
+						with_opacity(colour, self.settings.opacity)
+					});
+
+				let hanging_indents = self.settings.hanging_indents;
+				let maximum_lines = slide_options.maximum_lines;
+				let bullet_layout = |section: &Section| {
+					BulletLayout::new(section.layout, hanging_indents, maximum_lines)
+				};
+
+				// The lines past the maximum are cut off before the text is laid out, so
+				// that the lines that are left are scaled to fit the screen
+				let mut spans = split_colour_spans(text);
+				if let Some(maximum_lines) = maximum_lines {
+					spans = limit_lines(spans, maximum_lines, &self.ellipsis);
+				}
+
+				// Title slides show the lines after the first one smaller
+				let is_title = slide_options.slide_type == Some(SlideType::Title);
+				let mut spans = spans
+					.into_iter()
+					.map(|span| (span, 1.0))
+					.collect::<Vec<_>>();
+				if is_title {
+					spans = split_title_spans(
+						spans.into_iter().map(|(span, _)| span),
+						slide_options
+							.subtitle_scale
+							.unwrap_or(DEFAULT_SUBTITLE_SCALE),
+					);
+				}
+				let spans = match revealed_lines {
+					Some(revealed_lines) => split_revealed_spans(spans, revealed_lines),
+					None => spans
+						.into_iter()
+						.map(|(span, relative_scale)| (span, relative_scale, true))
+						.collect(),
+				};
+				let relative_scales = spans
+					.iter()
+					.map(|&(_, relative_scale, _)| relative_scale)
+					.collect::<Vec<_>>();
+				let revealed = spans
+					.iter()
+					.map(|&(_, _, is_revealed)| is_revealed)
+					.collect::<Vec<_>>();
+
 				// Start with an unscaled, non-centered layout in the top-left corner
+				// The layout stays non-centered, with the final position being calculated
+				// for the top-left corner
 				let mut section = Section::default()
-					.add_text(
-						Text::new(text)
-							.with_scale(base_scale)
-							.with_color(self.foreground_colour),
+					.with_text(
+						spans
+							.into_iter()
+							.map(|(span, relative_scale, is_revealed)| {
+								let colour = if is_revealed {
+									span.colour.map_or(foreground_colour, |colour| {
+										with_opacity(colour, self.settings.opacity)
+									})
+								} else {
+									HIDDEN_COLOUR
+								};
+
+								Text::new(span.text)
+									.with_scale(base_scale * relative_scale)
+									.with_color(colour)
+							})
+							.collect(),
 					)
 					.with_layout(NON_CENTERED_LAYOUT)
 					.with_bounds((f32::INFINITY, f32::INFINITY));
 
-				// Get the dimensions of it with the base scale so that it can be scaled
-				// to fit the usable space
-				let unscaled_section_dimensions = self
-					.glyph_brush
-					.glyph_bounds(&section)
-					.expect("the section is not empty");
-
-				// Calculate the new scale and set the final values for the section
-				let scaling_factor = calculate_scaling_factor(
-					usable_width,
-					usable_height,
-					unscaled_section_dimensions.width(),
-					unscaled_section_dimensions.height(),
-				);
-				let new_scale = base_scale * scaling_factor;
-
-				let scaled_section_width = unscaled_section_dimensions.width() * scaling_factor;
-
-				// There's only one text element, so this is safe to do
-				section.text[0].scale = new_scale.into();
-				section.layout = Layout::default()
-					.h_align(HorizontalAlign::Left)
-					.v_align(VerticalAlign::Center);
-				// The reason the calculations for X and Y are different is that the
-				// alignment horizontally and vertically is different
-				section.screen_position = (
-					(screen_width - scaled_section_width) / 2.0,
-					screen_height / 2.0,
-				);
-				section.bounds = (
-					usable_width + FLOATING_POINT_IMPRECISION_ACCOMMODATION,
-					usable_height,
+				// Scrolling slides are only animated if animations are enabled, and are
+				// otherwise shown like any other slide
+				let is_scrolling = self.settings.animations_enabled
+					&& slide_options.slide_type == Some(SlideType::Scroll);
+				if !is_scrolling {
+					self.scroll_animation = None;
+				} else if self
+					.scroll_animation
+					.as_ref()
+					.map_or(true, |animation| &animation.text != text)
+				{
+					self.scroll_animation = Some(ScrollAnimation {
+						text:       text.clone(),
+						start_time: Instant::now(),
+						duration:   slide_options
+							.scroll_duration
+							.unwrap_or(DEFAULT_SCROLL_DURATION),
+					});
+				}
+				// Scrolling text only needs to fit the width of the screen
+				let fit_mode = if is_scrolling {
+					FitMode::Width
+				} else {
+					self.settings.fit_mode
+				};
+
+				// Measuring the text is the slowest part of rendering it, so it's only done
+				// when something that affects the measurements has changed
+				let layout_key = TextLayoutKey {
+					text: text.clone(),
+					maximum_lines,
+					relative_scales: relative_scales.clone(),
+					font_size: slide_options.font_size,
+					scale: slide_options.scale,
+					usable_size: (usable_width, usable_height),
+					base_scale,
+					fit_mode,
+				};
+				let cached_layout = self
+					.text_layout_cache
+					.as_ref()
+					.filter(|cache| cache.key == layout_key)
+					.map(|cache| cache.scaled_layout);
+				let (new_scale, scaled_section_width, scaled_section_height) =
+					if let Some(scaled_layout) = cached_layout {
+						scaled_layout
+					} else {
+						let scaled_layout = if let Some(font_size) = slide_options.font_size {
+							// With an absolute font size, the text is wrapped within the usable
+							// width instead of being scaled to fit it
+							let new_scale = base_scale * font_size * PIXELS_PER_POINT;
+							for (section_text, relative_scale) in
+								section.text.iter_mut().zip(&relative_scales)
+							{
+								section_text.scale = (new_scale * relative_scale).into();
+							}
+							section.bounds = (usable_width, f32::INFINITY);
+
+							let section_dimensions = self
+								.glyph_brush
+								.glyph_bounds_custom_layout(&section, &bullet_layout(&section))
+								.expect("the section is not empty");
+
+							(
+								new_scale,
+								section_dimensions.width(),
+								section_dimensions.height(),
+							)
+						} else {
+							// Get the dimensions of it with the base scale so that it can be
+							// scaled to fit the usable space
+							let unscaled_section_dimensions = self
+								.glyph_brush
+								.glyph_bounds_custom_layout(&section, &bullet_layout(&section))
+								.expect("the section is not empty");
+
+							// Calculate the new scale
+							let scaling_factor = calculate_scaling_factor(
+								fit_mode,
+								usable_width,
+								usable_height,
+								unscaled_section_dimensions.width(),
+								unscaled_section_dimensions.height(),
+							) * slide_options.scale.unwrap_or(1.0);
+
+							(
+								base_scale * scaling_factor,
+								unscaled_section_dimensions.width() * scaling_factor,
+								unscaled_section_dimensions.height() * scaling_factor,
+							)
+						};
+						self.text_layout_cache = Some(CachedTextLayout {
+							key: layout_key,
+							scaled_layout,
+						});
+
+						scaled_layout
+					};
+
+				// Position the section within the screen
+				let mut text_layout = calculate_text_layout(
+					(screen_width, screen_height),
+					(usable_width, usable_height),
+					(scaled_section_width, scaled_section_height),
+					self.settings.fit_overflow,
+					slide_options.vertical_alignment.unwrap_or_default(),
 				);
+				// Scrolling text moves from just below the screen to just above it
+				if let Some(animation) = &self.scroll_animation {
+					text_layout.position.1 = screen_height
+						- animation.progress() * (screen_height + scaled_section_height);
+				}
+
+				// Set the final values for the section
+				for (section_text, relative_scale) in section.text.iter_mut().zip(&relative_scales)
+				{
+					section_text.scale = (new_scale * relative_scale).into();
+				}
+				// Glyphs are positioned relative to this, so keeping it on a whole pixel
+				// keeps their edges crisp at fractional scale factors
+				section.screen_position = round_to_pixel(text_layout.position);
+				section.bounds = text_layout.bounds;
+				// Title slides center each line unless they're aligned otherwise
+				let horizontal_alignment = slide_options
+					.horizontal_alignment
+					.or(is_title.then_some(HorizontalAlignment::Center));
+				if let Some(horizontal_alignment) = horizontal_alignment {
+					let (h_align, x) = align_horizontally(
+						horizontal_alignment,
+						screen_width,
+						usable_width,
+						text_layout.position.0,
+						scaled_section_width,
+					);
+					section.layout = section.layout.h_align(h_align);
+					section.screen_position.0 = round_to_pixel((x, 0.0)).0;
+				}
+
+				// Queue the outline beneath the text, if there is one
+				// Since `glyph_brush` doesn't support strokes, this is approximated by
+				// drawing the text several times around its position
+				if let Some(outline_colour) = self.settings.text_outline_colour {
+					let outline_width =
+						self.settings.text_outline_width * self.window.scale_factor() as f32;
+					let outline_colour = with_opacity(outline_colour, self.settings.opacity);
+					for step in 0..TEXT_OUTLINE_STEPS {
+						let angle = step as f32 / TEXT_OUTLINE_STEPS as f32 * TAU;
+						let mut outline_section = section.clone();
+						for (outline_text, &is_revealed) in
+							outline_section.text.iter_mut().zip(&revealed)
+						{
+							outline_text.extra.color = if is_revealed {
+								outline_colour
+							} else {
+								HIDDEN_COLOUR
+							};
+						}
+						outline_section.screen_position.0 += angle.cos() * outline_width;
+						outline_section.screen_position.1 += angle.sin() * outline_width;
+						self.glyph_brush
+							.queue_custom_layout(&outline_section, &bullet_layout(&section));
+					}
+				}
 
 				// Queue the finished section
-				self.glyph_brush.queue(&section);
+				self.glyph_brush
+					.queue_custom_layout(&section, &bullet_layout(&section));
 
 				// Draw the text
 				self.glyph_brush
 					.use_queue()
-					.draw(&mut self.encoder, &self.colour_view)
+					.draw(&mut self.encoder, &target_view)
 					.unwrap();
 			}
-			Slide::Image(image_path) => {
-				const RECT_VERTEX_INDICES: &[u16] = &[0, 1, 2, 2, 3, 0];
-
+			SlideContent::Image(image_path) => {
 				let CachedImageTexture {
-					dimensions: (image_width, image_height),
+					dimensions,
 					resource_view,
 				} = &self.image_texture_cache[image_path];
-				let (image_width, image_height) = (*image_width as f32, *image_height as f32);
+				let (dimensions, resource_view) = (*dimensions, resource_view.clone());
 
-				let scaling_factor = calculate_scaling_factor(
-					usable_width,
-					usable_height,
-					image_width,
-					image_height,
+				self.draw_texture(
+					resource_view,
+					dimensions,
+					(screen_width, screen_height),
+					image_rect,
+					slide_options.image_anchor.unwrap_or_default(),
+					slide_options
+						.nearest_threshold
+						.unwrap_or(IMAGE_SAMPLING_NEAREST_NEIGHBOUR_SCALING_FACTOR_MINIMUM),
 				);
-
-				let (scaled_width, scaled_height) =
-					(image_width * scaling_factor, image_height * scaling_factor);
-				let (x, y) = (
-					(screen_width - scaled_width) / 2.0,
-					(screen_height - scaled_height) / 2.0,
+			}
+			SlideContent::Images(image_paths) => {
+				let cells = calculate_grid_cells(
+					image_paths.len(),
+					image_rect,
+					screen_height * IMAGE_GRID_GAP_PERCENTAGE,
 				);
+				for (image_path, cell) in image_paths.iter().zip(cells) {
+					let CachedImageTexture {
+						dimensions,
+						resource_view,
+					} = &self.image_texture_cache[image_path];
+					let (dimensions, resource_view) = (*dimensions, resource_view.clone());
 
-				let vertices = screen_rect_to_vertices(
-					screen_width,
-					screen_height,
-					x,
-					y,
-					scaled_width,
-					scaled_height,
-				);
-				let (vertex_buffer, slice) = self
-					.factory
-					.create_vertex_buffer_with_slice(&vertices, RECT_VERTEX_INDICES);
+					self.draw_texture(
+						resource_view,
+						dimensions,
+						(screen_width, screen_height),
+						cell,
+						slide_options.image_anchor.unwrap_or_default(),
+						slide_options
+							.nearest_threshold
+							.unwrap_or(IMAGE_SAMPLING_NEAREST_NEIGHBOUR_SCALING_FACTOR_MINIMUM),
+					);
+				}
+			}
+			SlideContent::Video(video_path) => {
+				// Start the playback if this slide was only just switched to, unless it
+				// already failed to start
+				if self
+					.video_playback
+					.as_ref()
+					.map_or(true, |playback| &playback.video_path != video_path)
+					&& self.failed_video_path.as_ref() != Some(video_path)
+				{
+					self.video_playback = VideoPlayback::start(
+						&mut self.factory,
+						video_path,
+						self.video_info_cache[video_path],
+					);
+					if self.video_playback.is_none() {
+						self.failed_video_path = Some(video_path.clone());
+					}
+				}
 
-				let image_sampler =
-					if scaling_factor >= IMAGE_SAMPLING_NEAREST_NEIGHBOUR_SCALING_FACTOR_MINIMUM {
-						self.image_sampler_nearest_neighbour.clone()
-					} else {
-						self.image_sampler_anisotropic.clone()
-					};
+				if let Some(playback) = &mut self.video_playback {
+					// Upload the latest frame, if a new one is due
+					if let Some(frame) = playback.stream.take_due_frame() {
+						let (frame_width, frame_height, ..) =
+							playback.texture.get_info().kind.get_dimensions();
+						let image_info = ImageInfoCommon {
+							xoffset: 0,
+							yoffset: 0,
+							zoffset: 0,
+							width:   frame_width,
+							height:  frame_height,
+							depth:   0,
+							format:  (),
+							mipmap:  0,
+						};
+						let frame_chunks = slice_as_chunks::<u8, 4>(frame.as_slice());
+						if self
+							.encoder
+							.update_texture::<ColourSurface, ColourFormat>(
+								&playback.texture,
+								None,
+								image_info,
+								frame_chunks.0.as_slice(),
+							)
+							.is_ok()
+						{
+							playback.has_frame = true;
+						}
+					}
+
+					if playback.has_frame {
+						let (dimensions, resource_view) =
+							(playback.dimensions, playback.resource_view.clone());
+
+						self.draw_texture(
+							resource_view,
+							dimensions,
+							(screen_width, screen_height),
+							usable_rect,
+							slide_options.image_anchor.unwrap_or_default(),
+							slide_options
+								.nearest_threshold
+								.unwrap_or(IMAGE_SAMPLING_NEAREST_NEIGHBOUR_SCALING_FACTOR_MINIMUM),
+						);
+					}
+				}
+			}
+			SlideContent::Empty => {}
+		}
+
+		if let Some(caption) = caption {
+			self.draw_caption(
+				caption,
+				caption_rect,
+				slide_options
+					.foreground_colour
+					.map_or(foreground_colour, |colour| {
+						with_opacity(colour, self.settings.opacity)
+					}),
+				&target_view,
+			);
+		}
+
+		// Draw the watermark on top of the slide
+		// Empty slides, including the blanked screen, are left empty
+		let watermark = self
+			.settings
+			.watermark
+			.as_ref()
+			.filter(|_| slide.content != SlideContent::Empty)
+			.and_then(|watermark_path| self.image_texture_cache.get(watermark_path))
+			.map(|texture| (texture.dimensions, texture.resource_view.clone()));
+		if let Some((dimensions, resource_view)) = watermark {
+			self.draw_watermark(resource_view, dimensions, (screen_width, screen_height));
+		}
+
+		// Draw the progress bar and slide number on top of the slide, if enabled and
+		// the overlays aren't hidden
+		let slide_position = slide_position.filter(|_| slide.content != SlideContent::Empty);
+		if let Some((slide_position, slide_count)) =
+			slide_position.filter(|_| self.settings.show_progress_bar && self.overlays_visible)
+		{
+			let progress = (slide_position + 1) as f32 / slide_count as f32;
+			let height = (PROGRESS_BAR_HEIGHT * self.window.scale_factor() as f32).round();
+			self.draw_rect(
+				with_opacity(self.settings.foreground_colour, self.settings.opacity),
+				screen_width,
+				screen_height,
+				0.0,
+				screen_height - height,
+				(screen_width * progress).round(),
+				height,
+			);
+		}
+		if let Some((slide_position, slide_count)) =
+			slide_position.filter(|_| self.settings.show_slide_numbers && self.overlays_visible)
+		{
+			self.draw_slide_number(
+				format!("{} / {slide_count}", slide_position + 1).as_str(),
+				(screen_width, screen_height),
+				&target_view,
+			);
+		}
+
+		// Draw the design guides on top of the slide, if enabled
+		if self.guides_visible && self.overlays_visible {
+			self.draw_guides((screen_width, screen_height), (usable_width, usable_height));
+		}
+
+		// Draw the notification on top of the slide, if there is one
+		if let Some(notification) = &self.notification {
+			if !self.overlays_visible {
+				self.notification = None;
+			} else if notification.expiry_time > Instant::now() {
+				let section = Section::default()
+					.add_text(
+						Text::new(notification.message.as_str())
+							.with_scale(NOTIFICATION_FONT_SIZE * self.window.scale_factor() as f32)
+							.with_color(self.settings.foreground_colour),
+					)
+					.with_layout(
+						Layout::default()
+							.h_align(HorizontalAlign::Center)
+							.v_align(VerticalAlign::Bottom),
+					)
+					.with_screen_position(round_to_pixel((
+						screen_width / 2.0,
+						screen_height * (1.0 - NOTIFICATION_MARGIN_PERCENTAGE),
+					)));
+
+				self.glyph_brush.queue(&section);
+				self.glyph_brush
+					.use_queue()
+					.draw(&mut self.encoder, &target_view)
+					.unwrap();
+			} else {
+				self.notification = None;
+			}
+		}
 
-				self.image_pipeline_data.current_texture =
-					Some((resource_view.clone(), image_sampler));
-				self.image_pipeline_data.vertex_buffer = Some(vertex_buffer);
+		// Draw the help overlay on top of everything else on the slide
+		if let Some(keybindings) = self.help.take() {
+			self.draw_help(&keybindings, (screen_width, screen_height), &target_view);
+			self.help = Some(keybindings);
+		}
 
-				self.encoder
-					.draw(&slice, &self.image_pipeline, &self.image_pipeline_data);
+		// Draw the mirrored output to the screen
+		if let (Some(mirror), Some(mirror_target)) = (self.settings.mirror, &self.mirror_target) {
+			let mut vertices = screen_rect_to_vertices(
+				screen_width,
+				screen_height,
+				0.0,
+				0.0,
+				screen_width,
+				screen_height,
+			);
+			// Rendered textures have their rows in the opposite order to images, so
+			// they're already flipped vertically
+			if mirror == Mirror::Horizontal {
+				for vertex in &mut vertices {
+					vertex.uv = [1.0 - vertex.uv[0], 1.0 - vertex.uv[1]];
+				}
 			}
-			Slide::Empty => {}
+
+			self.image_pipeline_data.render_target = self.colour_view.clone();
+			self.draw_textured_rect(
+				mirror_target.resource_view.clone(),
+				self.image_sampler_nearest_neighbour.clone(),
+				NO_TINT,
+				vertices,
+				false,
+			);
+		}
+
+		// The spotlight is drawn after mirroring, so that it's always around the
+		// cursor
+		if self.spotlight_enabled {
+			self.draw_spotlight((screen_width, screen_height));
 		}
 
 		self.encoder.flush(&mut self.device);
+		if self.screenshot_requested {
+			self.screenshot_requested = false;
+			self.screenshot = Some(self.read_frame(window_size));
+		}
 		self.gl_surface.swap_buffers(&self.gl_context).unwrap();
 		self.device.cleanup();
 	}
 
+	#[must_use]
 	pub fn get_window(&self) -> &Window {
 		&self.window
 	}
+
+	/// Changes the colours used for rendering, such as when switching themes.
+	pub fn set_colours(
+		&mut self,
+		foreground_colour: LinearRgbaColour,
+		background_colour: LinearRgbaColour,
+	) {
+		self.settings.foreground_colour = foreground_colour;
+		self.settings.background_colour = background_colour;
+		self.window.request_redraw();
+	}
+
+	/// Briefly shows a message on top of the current slide.
+	pub fn show_notification<S>(&mut self, message: S)
+	where
+		S: Into<String>,
+	{
+		self.notification = Some(Notification {
+			message:     message.into(),
+			expiry_time: Instant::now() + NOTIFICATION_DURATION,
+		});
+		self.window.request_redraw();
+	}
+
+	/// Shows or hides all presenter overlays at once, such as for taking a
+	/// clean screenshot.
+	pub fn toggle_overlays(&mut self) {
+		self.overlays_visible = !self.overlays_visible;
+		self.window.request_redraw();
+	}
+
+	/// Shows or hides the design guides.
+	pub fn toggle_guides(&mut self) {
+		self.guides_visible = !self.guides_visible;
+		self.window.request_redraw();
+	}
+
+	/// Turns the spotlight on or off.
+	pub fn toggle_spotlight(&mut self) {
+		self.spotlight_enabled = !self.spotlight_enabled;
+		self.window.request_redraw();
+	}
+
+	/// Shows a list of keybindings and their actions over a dimmed screen.
+	pub fn show_help(&mut self, keybindings: Vec<(&'static str, &'static str)>) {
+		self.help = Some(keybindings);
+		self.window.request_redraw();
+	}
+
+	/// Hides the help overlay, returning whether it was shown.
+	pub fn hide_help(&mut self) -> bool {
+		let was_shown = self.help.take().is_some();
+		if was_shown {
+			self.window.request_redraw();
+		}
+
+		was_shown
+	}
+
+	/// Captures the next frame once it's drawn, so that it can be taken with
+	/// [`Self::take_screenshot`].
+	pub fn request_screenshot(&mut self) {
+		self.screenshot_requested = true;
+		self.window.request_redraw();
+	}
+
+	/// Takes the last captured frame, if there is one.
+	pub fn take_screenshot(&mut self) -> Option<RgbaImage> {
+		self.screenshot.take()
+	}
+
+	/// Records the position of the cursor within the window, which the
+	/// spotlight follows.
+	pub fn set_cursor_position(&mut self, position: PhysicalPosition<f64>) {
+		self.cursor_position = Some((position.x as f32, position.y as f32));
+		if self.spotlight_enabled {
+			self.window.request_redraw();
+		}
+	}
+
+	/// Replaces a single cached image, such as after it has been edited,
+	/// leaving the rest of the cache untouched.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the image isn't part of the presentation, or if it
+	/// can't be prepared for rendering.
+	pub fn replace_image(&mut self, image_path: &str, image: &DynamicImage) -> AnyhowResult<()> {
+		let Some((_, cached_image_texture)) = self
+			.image_texture_cache
+			.iter_mut()
+			.find(|(cached_image_path, _)| cached_image_path.as_str() == image_path)
+		else {
+			return Err(anyhow!(
+				"the image \"{image_path}\" isn't part of the presentation"
+			));
+		};
+
+		*cached_image_texture = convert_image_to_texture(&mut self.factory, image_path, image)?;
+
+		Ok(())
+	}
+
+	/// The time at which the screen next needs to be redrawn without any user
+	/// input, such as for the next frame of a video or to clear a
+	/// notification.
+	///
+	/// Nothing is drawn while the window has no drawable size, such as when
+	/// it's minimised, so there's no redraw to wait for until it's restored.
+	#[must_use]
+	pub fn get_next_redraw_time(&self) -> Option<Instant> {
+		if !is_drawable_size(self.window.inner_size()) {
+			return None;
+		}
+
+		let next_frame_time = self
+			.video_playback
+			.as_ref()
+			.and_then(|playback| playback.stream.next_frame_time());
+		let notification_expiry_time = self
+			.notification
+			.as_ref()
+			.map(|notification| notification.expiry_time);
+
+		let scroll_frame_time = self
+			.scroll_animation
+			.as_ref()
+			.filter(|animation| !animation.is_finished())
+			.map(|_| Instant::now() + ANIMATION_FRAME_INTERVAL);
+
+		next_frame_time
+			.into_iter()
+			.chain(notification_expiry_time)
+			.chain(scroll_frame_time)
+			.min()
+	}
+
+	/// Whether the current slide is a scrolling slide that has finished
+	/// scrolling past.
+	#[must_use]
+	pub fn is_scroll_finished(&self) -> bool {
+		self.scroll_animation
+			.as_ref()
+			.is_some_and(ScrollAnimation::is_finished)
+	}
+
+	/// Scrolls the current scrolling slide past again from the start.
+	pub fn restart_scroll(&mut self) {
+		if let Some(animation) = &mut self.scroll_animation {
+			animation.start_time = Instant::now();
+			self.window.request_redraw();
+		}
+	}
+
+	/// Draws a texture scaled to fit an area of the screen, such as the usable
+	/// space, placed at the anchor within it.
+	///
+	/// The area is its position and size, in pixels from the top-left corner
+	/// of the screen.
+	fn draw_texture(
+		&mut self,
+		resource_view: ShaderResourceView<Resources, Vec4<f32>>,
+		(texture_width, texture_height): (u32, u32),
+		(screen_width, screen_height): (f32, f32),
+		(area_x, area_y, area_width, area_height): (f32, f32, f32, f32),
+		anchor: Anchor,
+		nearest_neighbour_threshold: f32,
+	) {
+		let (texture_width, texture_height) = (texture_width as f32, texture_height as f32);
+
+		let scaling_factor = calculate_scaling_factor(
+			FitMode::Both,
+			area_width,
+			area_height,
+			texture_width,
+			texture_height,
+		);
+
+		let (scaled_width, scaled_height) = (
+			texture_width * scaling_factor,
+			texture_height * scaling_factor,
+		);
+		let (x, y) = anchor_in_area(
+			(scaled_width, scaled_height),
+			(area_x, area_y, area_width, area_height),
+			anchor,
+		);
+
+		let image_sampler = if scaling_factor >= nearest_neighbour_threshold {
+			self.image_sampler_nearest_neighbour.clone()
+		} else {
+			self.image_sampler_anisotropic.clone()
+		};
+
+		self.draw_textured_rect(
+			resource_view,
+			image_sampler,
+			NO_TINT,
+			screen_rect_to_vertices(
+				screen_width,
+				screen_height,
+				x,
+				y,
+				scaled_width,
+				scaled_height,
+			),
+			false,
+		);
+	}
+
+	/// Draws a background image scaled to cover the whole screen, centered so
+	/// that any overflow is cropped evenly from both sides.
+	fn draw_background_image(
+		&mut self,
+		resource_view: ShaderResourceView<Resources, Vec4<f32>>,
+		(texture_width, texture_height): (u32, u32),
+		(screen_width, screen_height): (f32, f32),
+	) {
+		let (texture_width, texture_height) = (texture_width as f32, texture_height as f32);
+
+		let scaling_factor = (screen_width / texture_width).max(screen_height / texture_height);
+
+		let (scaled_width, scaled_height) = (
+			texture_width * scaling_factor,
+			texture_height * scaling_factor,
+		);
+
+		self.draw_textured_rect(
+			resource_view,
+			self.image_sampler_anisotropic.clone(),
+			NO_TINT,
+			screen_rect_to_vertices(
+				screen_width,
+				screen_height,
+				(screen_width - scaled_width) / 2.0,
+				(screen_height - scaled_height) / 2.0,
+				scaled_width,
+				scaled_height,
+			),
+			false,
+		);
+	}
+
+	/// Draws the watermark scaled down to fit in its corner of the screen,
+	/// blended with the slide beneath it.
+	fn draw_watermark(
+		&mut self,
+		resource_view: ShaderResourceView<Resources, Vec4<f32>>,
+		(texture_width, texture_height): (u32, u32),
+		(screen_width, screen_height): (f32, f32),
+	) {
+		let (texture_width, texture_height) = (texture_width as f32, texture_height as f32);
+
+		let scaling_factor = calculate_scaling_factor(
+			FitMode::Both,
+			screen_width * WATERMARK_MAXIMUM_PERCENTAGES.0,
+			screen_height * WATERMARK_MAXIMUM_PERCENTAGES.1,
+			texture_width,
+			texture_height,
+		);
+
+		let (scaled_width, scaled_height) = (
+			texture_width * scaling_factor,
+			texture_height * scaling_factor,
+		);
+		let margin = screen_height * WATERMARK_MARGIN_PERCENTAGE;
+		let (anchor_x, anchor_y) = self.settings.watermark_position.to_fractions();
+		let (x, y) = (
+			margin + (screen_width - 2.0 * margin - scaled_width) * anchor_x,
+			margin + (screen_height - 2.0 * margin - scaled_height) * anchor_y,
+		);
+
+		let mut tint = NO_TINT;
+		tint[3] = self.settings.watermark_opacity * self.settings.opacity;
+
+		self.draw_textured_rect(
+			resource_view,
+			self.image_sampler_anisotropic.clone(),
+			tint,
+			screen_rect_to_vertices(
+				screen_width,
+				screen_height,
+				x,
+				y,
+				scaled_width,
+				scaled_height,
+			),
+			true,
+		);
+	}
+
+	/// Dims the whole screen except for a circle around the cursor, or the
+	/// center of the screen if the cursor hasn't moved yet.
+	fn draw_spotlight(&mut self, (screen_width, screen_height): (f32, f32)) {
+		const RECT_VERTEX_INDICES: &[u16] = &[0, 1, 2, 2, 3, 0];
+
+		let (vertex_buffer, slice) = self.factory.create_vertex_buffer_with_slice(
+			&screen_rect_to_vertices(
+				screen_width,
+				screen_height,
+				0.0,
+				0.0,
+				screen_width,
+				screen_height,
+			),
+			RECT_VERTEX_INDICES,
+		);
+		let centre = self
+			.cursor_position
+			.unwrap_or((screen_width / 2.0, screen_height / 2.0));
+
+		let spotlight_pipeline_data = spotlight_pipeline::Data {
+			vertex_buffer,
+			colour: with_opacity(SPOTLIGHT_DIM_COLOUR, self.settings.opacity),
+			screen_size: [screen_width, screen_height],
+			centre: [centre.0, centre.1],
+			radius: screen_height * SPOTLIGHT_RADIUS_PERCENTAGE,
+			edge_width: SPOTLIGHT_EDGE_WIDTH * self.window.scale_factor() as f32,
+			render_target: self.colour_view.clone(),
+		};
+		self.encoder
+			.draw(&slice, &self.spotlight_pipeline, &spotlight_pipeline_data);
+	}
+
+	/// Reads back the frame that has just been drawn to the window, before it's
+	/// presented.
+	fn read_frame(&mut self, size: PhysicalSize<u32>) -> RgbaImage {
+		let mut image = RgbaImage::new(size.width, size.height);
+		// SAFETY: The default framebuffer is the size of the window, and the
+		// image's buffer holds exactly that many tightly-packed RGBA pixels
+		unsafe {
+			self.device.with_gl(|gl| {
+				gl.BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+				gl.PixelStorei(gl::PACK_ALIGNMENT, 1);
+				gl.ReadPixels(
+					0,
+					0,
+					size.width as gl::types::GLsizei,
+					size.height as gl::types::GLsizei,
+					gl::RGBA,
+					gl::UNSIGNED_BYTE,
+					image.as_mut_ptr().cast(),
+				);
+			});
+		}
+		// OpenGL's rows start from the bottom
+		flip_vertical_in_place(&mut image);
+
+		image
+	}
+
+	/// Draws the slide number in the bottom-right corner of the screen, or the
+	/// bottom-left corner if the watermark is in the way.
+	///
+	/// It's drawn at a fixed size, independent of the slide's text.
+	fn draw_slide_number(
+		&mut self,
+		text: &str,
+		(screen_width, screen_height): (f32, f32),
+		target_view: &RenderTargetView<Resources, ColourFormat>,
+	) {
+		let margin = screen_height * SLIDE_NUMBER_MARGIN_PERCENTAGE;
+		let (h_align, x) = if self.settings.watermark.is_some()
+			&& self.settings.watermark_position == Anchor::BottomRight
+		{
+			(HorizontalAlign::Left, margin)
+		} else {
+			(HorizontalAlign::Right, screen_width - margin)
+		};
+
+		self.glyph_brush.queue(
+			Section::default()
+				.add_text(
+					Text::new(text)
+						.with_scale(SLIDE_NUMBER_FONT_SIZE * self.window.scale_factor() as f32)
+						.with_color(with_opacity(
+							self.settings.foreground_colour,
+							self.settings.opacity,
+						)),
+				)
+				.with_layout(
+					Layout::default()
+						.h_align(h_align)
+						.v_align(VerticalAlign::Bottom),
+				)
+				.with_screen_position(round_to_pixel((x, screen_height - margin))),
+		);
+		self.glyph_brush
+			.use_queue()
+			.draw(&mut self.encoder, target_view)
+			.unwrap();
+	}
+
+	/// Draws the caption of an image slide, fitted to the area below the
+	/// image with each line centered.
+	///
+	/// Spans without a colour of their own are drawn in `colour`.
+	fn draw_caption(
+		&mut self,
+		caption: &str,
+		(area_x, area_y, area_width, area_height): (f32, f32, f32, f32),
+		colour: LinearRgbaColour,
+		target_view: &RenderTargetView<Resources, ColourFormat>,
+	) {
+		let layout = Layout::default()
+			.h_align(HorizontalAlign::Center)
+			.v_align(VerticalAlign::Center);
+
+		// Captions can be coloured the same way as text slides
+		let spans = split_colour_spans(caption);
+		let opacity = self.settings.opacity;
+		let texts = |scale: f32| {
+			spans
+				.iter()
+				.map(|span| {
+					Text::new(span.text).with_scale(scale).with_color(
+						span.colour
+							.map_or(colour, |colour| with_opacity(colour, opacity)),
+					)
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// Measure the caption unscaled, then scale it to fit the area
+		let section = Section::default()
+			.with_text(texts(1.0))
+			.with_layout(layout)
+			.with_bounds((f32::INFINITY, f32::INFINITY));
+		let Some(bounds) = self.glyph_brush.glyph_bounds(&section) else {
+			return;
+		};
+		let scaling_factor = calculate_scaling_factor(
+			FitMode::Both,
+			area_width,
+			area_height,
+			bounds.width(),
+			bounds.height(),
+		);
+
+		self.glyph_brush.queue(
+			Section::default()
+				.with_text(texts(scaling_factor))
+				.with_layout(layout)
+				.with_bounds((f32::INFINITY, f32::INFINITY))
+				.with_screen_position(round_to_pixel((
+					area_x + area_width / 2.0,
+					area_y + area_height / 2.0,
+				))),
+		);
+		self.glyph_brush
+			.use_queue()
+			.draw(&mut self.encoder, target_view)
+			.unwrap();
+	}
+
+	/// Dims the whole screen and lists keybindings in two columns, with the
+	/// keys on the left and their actions on the right.
+	fn draw_help(
+		&mut self,
+		keybindings: &[(&str, &str)],
+		(screen_width, screen_height): (f32, f32),
+		target_view: &RenderTargetView<Resources, ColourFormat>,
+	) {
+		self.draw_textured_rect(
+			self.solid_texture.clone(),
+			self.image_sampler_nearest_neighbour.clone(),
+			with_opacity(HELP_DIM_COLOUR, self.settings.opacity),
+			screen_rect_to_vertices(
+				screen_width,
+				screen_height,
+				0.0,
+				0.0,
+				screen_width,
+				screen_height,
+			),
+			true,
+		);
+
+		let scale_factor = self.window.scale_factor() as f32;
+		let text_colour = with_opacity(HELP_TEXT_COLOUR, self.settings.opacity);
+		let keys = keybindings
+			.iter()
+			.map(|(key, _)| *key)
+			.collect::<Vec<_>>()
+			.join("\n");
+		let actions = keybindings
+			.iter()
+			.map(|(_, action)| *action)
+			.collect::<Vec<_>>()
+			.join("\n");
+		let half_gap = HELP_COLUMN_GAP * scale_factor / 2.0;
+		for (text, h_align, x) in [
+			(
+				keys.as_str(),
+				HorizontalAlign::Right,
+				screen_width / 2.0 - half_gap,
+			),
+			(
+				actions.as_str(),
+				HorizontalAlign::Left,
+				screen_width / 2.0 + half_gap,
+			),
+		] {
+			self.glyph_brush.queue(
+				Section::default()
+					.add_text(
+						Text::new(text)
+							.with_scale(HELP_FONT_SIZE * scale_factor)
+							.with_color(text_colour),
+					)
+					.with_layout(
+						Layout::default()
+							.h_align(h_align)
+							.v_align(VerticalAlign::Center),
+					)
+					.with_screen_position(round_to_pixel((x, screen_height / 2.0))),
+			);
+		}
+		self.glyph_brush
+			.use_queue()
+			.draw(&mut self.encoder, target_view)
+			.unwrap();
+	}
+
+	/// Draws the outline of the usable space and lines through the center of
+	/// the screen.
+	fn draw_guides(
+		&mut self,
+		(screen_width, screen_height): (f32, f32),
+		(usable_width, usable_height): (f32, f32),
+	) {
+		// There's no blending, so the faint colour is mixed by hand
+		let guide_colour = with_opacity(
+			lerp_colour(
+				self.settings.background_colour,
+				self.settings.foreground_colour,
+				GUIDE_COLOUR_STRENGTH,
+			),
+			self.settings.opacity,
+		);
+		let line_width = (GUIDE_LINE_WIDTH * self.window.scale_factor() as f32).round();
+		let (usable_x, usable_y) = (
+			((screen_width - usable_width) / 2.0).round(),
+			((screen_height - usable_height) / 2.0).round(),
+		);
+		let (center_x, center_y) = (
+			((screen_width - line_width) / 2.0).round(),
+			((screen_height - line_width) / 2.0).round(),
+		);
+
+		for (x, y, width, height) in [
+			// The outline of the usable space
+			(usable_x, usable_y, usable_width, line_width),
+			(
+				usable_x,
+				usable_y + usable_height - line_width,
+				usable_width,
+				line_width,
+			),
+			(usable_x, usable_y, line_width, usable_height),
+			(
+				usable_x + usable_width - line_width,
+				usable_y,
+				line_width,
+				usable_height,
+			),
+			// The center lines
+			(center_x, 0.0, line_width, screen_height),
+			(0.0, center_y, screen_width, line_width),
+		] {
+			self.draw_rect(
+				guide_colour,
+				screen_width,
+				screen_height,
+				x,
+				y,
+				width,
+				height,
+			);
+		}
+	}
+
+	/// Draws a rect of a solid colour.
+	#[allow(clippy::too_many_arguments)]
+	fn draw_rect(
+		&mut self,
+		colour: LinearRgbaColour,
+		screen_width: f32,
+		screen_height: f32,
+		x: f32,
+		y: f32,
+		width: f32,
+		height: f32,
+	) {
+		self.draw_textured_rect(
+			self.solid_texture.clone(),
+			self.image_sampler_nearest_neighbour.clone(),
+			colour,
+			screen_rect_to_vertices(screen_width, screen_height, x, y, width, height),
+			false,
+		);
+	}
+
+	/// Draws a rect with a texture, multiplied by the tint colour.
+	///
+	/// If `blended` is `true`, the texture is blended with what's already drawn
+	/// according to its alpha, instead of replacing it.
+	fn draw_textured_rect(
+		&mut self,
+		resource_view: ShaderResourceView<Resources, Vec4<f32>>,
+		sampler: Sampler<Resources>,
+		tint: LinearRgbaColour,
+		vertices: [Vertex; 4],
+		blended: bool,
+	) {
+		const RECT_VERTEX_INDICES: &[u16] = &[0, 1, 2, 2, 3, 0];
+
+		let (vertex_buffer, slice) = self
+			.factory
+			.create_vertex_buffer_with_slice(&vertices, RECT_VERTEX_INDICES);
+
+		self.image_pipeline_data.current_texture = Some((resource_view, sampler));
+		self.image_pipeline_data.vertex_buffer = Some(vertex_buffer);
+		self.image_pipeline_data.tint = tint;
+
+		let pipeline = if blended {
+			&self.blended_image_pipeline
+		} else {
+			&self.image_pipeline
+		};
+		self.encoder
+			.draw(&slice, pipeline, &self.image_pipeline_data);
+	}
+}
+
+/// An offscreen texture that slides are rendered to when mirroring.
+struct MirrorTarget {
+	dimensions:         (u16, u16),
+	resource_view:      ShaderResourceView<Resources, Vec4<f32>>,
+	render_target_view: RenderTargetView<Resources, ColourFormat>,
+}
+
+impl MirrorTarget {
+	/// Creates a target with the provided dimensions.
+	///
+	/// Returns `None` if the texture couldn't be created, in which case the
+	/// output isn't mirrored.
+	fn new(factory: &mut Factory, dimensions: (u16, u16)) -> Option<Self> {
+		let (_, resource_view, render_target_view) = factory
+			.create_render_target::<ColourFormat>(dimensions.0, dimensions.1)
+			.ok()?;
+
+		Some(Self {
+			dimensions,
+			resource_view,
+			render_target_view,
+		})
+	}
+}
+
+/// Everything that affects the measurements of a text slide.
+#[derive(PartialEq)]
+struct TextLayoutKey {
+	text:            String,
+	maximum_lines:   Option<usize>,
+	relative_scales: Vec<f32>,
+	font_size:       Option<f32>,
+	scale:           Option<f32>,
+	usable_size:     (f32, f32),
+	base_scale:      f32,
+	fit_mode:        FitMode,
+}
+
+/// The measurements of a text slide, as the scale and the scaled width and
+/// height of its text.
+struct CachedTextLayout {
+	key:           TextLayoutKey,
+	scaled_layout: (f32, f32, f32),
+}
+
+/// The state of a scrolling slide.
+struct ScrollAnimation {
+	/// The text of the slide, to tell when a different slide is shown.
+	text:       String,
+	start_time: Instant,
+	duration:   Duration,
+}
+
+impl ScrollAnimation {
+	/// How far through the scroll the animation is, from `0.0` to `1.0`.
+	fn progress(&self) -> f32 {
+		(self.start_time.elapsed().as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+	}
+
+	fn is_finished(&self) -> bool {
+		self.start_time.elapsed() >= self.duration
+	}
+}
+
+/// A message shown briefly on top of the slide.
+struct Notification {
+	message:     String,
+	expiry_time: Instant,
+}
+
+/// The state of the video that's currently being played.
+struct VideoPlayback {
+	video_path:    String,
+	dimensions:    (u32, u32),
+	stream:        VideoStream,
+	texture:       Texture<Resources, ColourSurface>,
+	resource_view: ShaderResourceView<Resources, Vec4<f32>>,
+	/// Whether a frame has been uploaded to the texture yet.
+	has_frame:     bool,
+}
+
+impl VideoPlayback {
+	/// Starts playing a video, preparing a texture for its frames to be
+	/// uploaded to.
+	///
+	/// Returns `None` if the playback couldn't be started, such as when the
+	/// video is too large for a texture, in which case the slide is left blank.
+	fn start(factory: &mut Factory, video_path: &str, info: VideoInfo) -> Option<Self> {
+		let (width, height) = info.dimensions;
+		let kind = Kind::D2(
+			u16::try_from(width).ok()?,
+			u16::try_from(height).ok()?,
+			AaMode::Single,
+		);
+		let texture = factory
+			.create_texture::<ColourSurface>(
+				kind,
+				1,
+				Bind::SHADER_RESOURCE,
+				Usage::Dynamic,
+				Some(ChannelType::Srgb),
+			)
+			.ok()?;
+		let resource_view = factory
+			.view_texture_as_shader_resource::<ColourFormat>(&texture, (0, 0), Swizzle::new())
+			.ok()?;
+		let stream = VideoStream::start(Path::new(video_path), info).ok()?;
+
+		Some(Self {
+			video_path: video_path.to_owned(),
+			dimensions: info.dimensions,
+			stream,
+			texture,
+			resource_view,
+			has_frame: false,
+		})
+	}
 }
 
 struct CachedImageTexture {
@@ -375,32 +1951,52 @@ fn convert_image_cache_to_textures<'a>(
 	let mut image_texture_cache = HashMap::new();
 
 	for (image_path, image) in image_cache {
-		let image_dimensions = image.dimensions();
-		let image_data = image.to_rgba8();
-		let (image_width, image_height) = image_data.dimensions();
-		let kind = Kind::D2(image_width as u16, image_height as u16, AaMode::Single);
-		let image_data_chunks = slice_as_chunks::<u8, 4>(image_data.as_raw().as_slice());
-		let (_, resource_view) = factory
-			.create_texture_immutable::<ColourFormat>(
-				kind,
-				Mipmap::Provided,
-				&[image_data_chunks.0.as_slice()],
-			)
-			.with_context(|| {
-				format!("unable to prepare the image \"{image_path}\" for rendering")
-			})?;
-		image_texture_cache.insert(
-			image_path,
-			CachedImageTexture {
-				dimensions: image_dimensions,
-				resource_view,
-			},
-		);
+		let image_texture = convert_image_to_texture(factory, image_path, &image)?;
+		image_texture_cache.insert(image_path, image_texture);
 	}
 
 	Ok(image_texture_cache)
 }
 
+fn convert_image_to_texture(
+	factory: &mut Factory,
+	image_path: &str,
+	image: &DynamicImage,
+) -> AnyhowResult<CachedImageTexture> {
+	let image_dimensions = image.dimensions();
+	let image_data = image.to_rgba8();
+	let (image_width, image_height) = image_data.dimensions();
+	let kind = Kind::D2(image_width as u16, image_height as u16, AaMode::Single);
+	let image_data_chunks = slice_as_chunks::<u8, 4>(image_data.as_raw().as_slice());
+	let (_, resource_view) = factory
+		.create_texture_immutable::<ColourFormat>(
+			kind,
+			Mipmap::Provided,
+			&[image_data_chunks.0.as_slice()],
+		)
+		.with_context(|| format!("unable to prepare the image \"{image_path}\" for rendering"))?;
+
+	Ok(CachedImageTexture {
+		dimensions: image_dimensions,
+		resource_view,
+	})
+}
+
+/// Calculates the position of a rect of the provided size placed at an anchor
+/// within an area, in pixels from the top-left corner of the screen.
+fn anchor_in_area(
+	(width, height): (f32, f32),
+	(area_x, area_y, area_width, area_height): (f32, f32, f32, f32),
+	anchor: Anchor,
+) -> (f32, f32) {
+	let (anchor_x, anchor_y) = anchor.to_fractions();
+
+	(
+		area_x + (area_width - width) * anchor_x,
+		area_y + (area_height - height) * anchor_y,
+	)
+}
+
 /// Converts a rect defined by coordinates in pixels to a set of vertices that
 /// use normalised coordinates for rendering.
 fn screen_rect_to_vertices(
@@ -411,34 +2007,37 @@ fn screen_rect_to_vertices(
 	width: f32,
 	height: f32,
 ) -> [Vertex; 4] {
+	// Screen coordinates start at the top-left, while normalised coordinates
+	// start at the bottom-left
 	let transform_x = |x: f32| -> f32 { (x / screen_width) * 2.0 - 1.0 };
-	let transform_y = |y: f32| -> f32 { (y / screen_height) * 2.0 - 1.0 };
+	let transform_y = |y: f32| -> f32 { 1.0 - (y / screen_height) * 2.0 };
 
 	[
 		// Top Right
 		Vertex {
-			pos: [transform_x(x + width), transform_y(y + height)],
+			pos: [transform_x(x + width), transform_y(y)],
 			uv:  [1.0, 0.0],
 		},
 		// Top Left
 		Vertex {
-			pos: [transform_x(x), transform_y(y + height)],
+			pos: [transform_x(x), transform_y(y)],
 			uv:  [0.0, 0.0],
 		},
 		// Bottom Left
 		Vertex {
-			pos: [transform_x(x), transform_y(y)],
+			pos: [transform_x(x), transform_y(y + height)],
 			uv:  [0.0, 1.0],
 		},
 		// Bottom Right
 		Vertex {
-			pos: [transform_x(x + width), transform_y(y)],
+			pos: [transform_x(x + width), transform_y(y + height)],
 			uv:  [1.0, 1.0],
 		},
 	]
 }
 
 fn calculate_scaling_factor(
+	fit_mode: FitMode,
 	usable_width: f32,
 	usable_height: f32,
 	unscaled_width: f32,
@@ -447,7 +2046,312 @@ fn calculate_scaling_factor(
 	let width_scaling_factor = usable_width / unscaled_width;
 	let height_scaling_factor = usable_height / unscaled_height;
 
-	width_scaling_factor.min(height_scaling_factor)
+	match fit_mode {
+		FitMode::Width => width_scaling_factor,
+		FitMode::Height => height_scaling_factor,
+		FitMode::Both => width_scaling_factor.min(height_scaling_factor),
+	}
+}
+
+/// The placement of a text section on the screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TextLayout {
+	/// The top-left corner of the section.
+	position: (f32, f32),
+	/// The size of the area the section is laid out within.
+	bounds:   (f32, f32),
+}
+
+/// Calculates where to place a text section with the provided (already
+/// scaled) dimensions on the screen.
+fn calculate_text_layout(
+	(screen_width, screen_height): (f32, f32),
+	(usable_width, usable_height): (f32, f32),
+	(section_width, section_height): (f32, f32),
+	fit_overflow: FitOverflow,
+	vertical_alignment: VerticalAlignment,
+) -> TextLayout {
+	/// Floating-point imprecision can cause text to
+	/// wrap when it's not supposed to because it's
+	/// ever-so-slightly larger than the bounds.
+	///
+	/// This value exists to account for that.
+	const FLOATING_POINT_IMPRECISION_ACCOMMODATION: f32 = 0.1;
+
+	// When only fitting to one dimension, the text may overflow the other
+	let align_overflow_to_start = fit_overflow == FitOverflow::Start;
+	let overflows_horizontally = section_width > usable_width;
+	let overflows_vertically = section_height > usable_height;
+
+	let x = if align_overflow_to_start && overflows_horizontally {
+		(screen_width - usable_width) / 2.0
+	} else {
+		(screen_width - section_width) / 2.0
+	};
+	let vertical_alignment = if align_overflow_to_start && overflows_vertically {
+		VerticalAlignment::Top
+	} else {
+		vertical_alignment
+	};
+	let y = match vertical_alignment {
+		VerticalAlignment::Top => (screen_height - usable_height) / 2.0,
+		VerticalAlignment::Center => (screen_height - section_height) / 2.0,
+		VerticalAlignment::Bottom => (screen_height + usable_height) / 2.0 - section_height,
+	};
+
+	TextLayout {
+		position: (x, y),
+		bounds:   (
+			usable_width.max(section_width) + FLOATING_POINT_IMPRECISION_ACCOMMODATION,
+			usable_height.max(section_height),
+		),
+	}
+}
+
+/// Calculates how to align each line of a text section that has been placed
+/// by [`calculate_text_layout`], with the section's left edge at `section_x`.
+///
+/// Returns the alignment for the section's layout, along with the horizontal
+/// screen position it's relative to: the left edge, middle, or right edge of
+/// the usable space. If the section overflows the usable space, it's the
+/// section's edge instead.
+fn align_horizontally(
+	horizontal_alignment: HorizontalAlignment,
+	screen_width: f32,
+	usable_width: f32,
+	section_x: f32,
+	section_width: f32,
+) -> (HorizontalAlign, f32) {
+	let usable_left = (screen_width - usable_width) / 2.0;
+	let usable_right = usable_left + usable_width;
+
+	match horizontal_alignment {
+		HorizontalAlignment::Left => (HorizontalAlign::Left, section_x.min(usable_left)),
+		HorizontalAlignment::Center => (HorizontalAlign::Center, section_x + section_width / 2.0),
+		HorizontalAlignment::Right => (
+			HorizontalAlign::Right,
+			(section_x + section_width).max(usable_right),
+		),
+	}
+}
+
+/// Divides an area into a grid of cells with gaps between them, with as many
+/// columns as rows or one more, such as side by side for two cells.
+///
+/// The area and cells are each a position and size. Rows are filled from
+/// left to right, and the last row is centered if it isn't full.
+fn calculate_grid_cells(
+	cell_count: usize,
+	(area_x, area_y, area_width, area_height): (f32, f32, f32, f32),
+	gap: f32,
+) -> Vec<(f32, f32, f32, f32)> {
+	if cell_count == 0 {
+		return Vec::new();
+	}
+
+	let column_count = (cell_count as f32).sqrt().ceil() as usize;
+	let row_count = cell_count.div_ceil(column_count);
+	let cell_width = (area_width - gap * (column_count - 1) as f32) / column_count as f32;
+	let cell_height = (area_height - gap * (row_count - 1) as f32) / row_count as f32;
+
+	(0..cell_count)
+		.map(|cell_index| {
+			let (row, column) = (cell_index / column_count, cell_index % column_count);
+			let cells_in_row = (cell_count - row * column_count).min(column_count);
+			let row_offset = (column_count - cells_in_row) as f32 * (cell_width + gap) / 2.0;
+
+			(
+				area_x + row_offset + column as f32 * (cell_width + gap),
+				area_y + row as f32 * (cell_height + gap),
+				cell_width,
+				cell_height,
+			)
+		})
+		.collect()
+}
+
+/// Splits an area between the image of a captioned slide, at the top, and its
+/// caption below it, with a gap between them.
+///
+/// The area and both parts are each a position and size.
+#[allow(clippy::type_complexity)]
+fn split_caption_area(
+	(area_x, area_y, area_width, area_height): (f32, f32, f32, f32),
+	gap: f32,
+) -> ((f32, f32, f32, f32), (f32, f32, f32, f32)) {
+	let image_height = area_height * CAPTIONED_IMAGE_HEIGHT_PERCENTAGE;
+	let caption_y = area_y + image_height + gap;
+
+	(
+		(area_x, area_y, area_width, image_height),
+		(
+			area_x,
+			caption_y,
+			area_width,
+			(area_y + area_height - caption_y).max(0.0),
+		),
+	)
+}
+
+/// Splits text spans at the end of the first line, so that the lines after it
+/// can be scaled down on title slides.
+///
+/// Returns each span along with its scale relative to the first line.
+fn split_title_spans<'a, I>(spans: I, subtitle_scale: f32) -> Vec<(TextSpan<'a>, f32)>
+where
+	I: IntoIterator<Item = TextSpan<'a>>,
+{
+	let mut title_spans = Vec::new();
+	let mut is_subtitle = false;
+
+	for span in spans {
+		if is_subtitle {
+			title_spans.push((span, subtitle_scale));
+			continue;
+		}
+
+		// The newline stays with the title, so that the title's line keeps its height
+		let Some(newline_index) = span.text.find('\n') else {
+			title_spans.push((span, 1.0));
+			continue;
+		};
+		let (title_text, subtitle_text) = span.text.split_at(newline_index + 1);
+		title_spans.push((
+			TextSpan {
+				text:   title_text,
+				colour: span.colour,
+			},
+			1.0,
+		));
+		if !subtitle_text.is_empty() {
+			title_spans.push((
+				TextSpan {
+					text:   subtitle_text,
+					colour: span.colour,
+				},
+				subtitle_scale,
+			));
+		}
+		is_subtitle = true;
+	}
+
+	title_spans
+}
+
+/// Limits text spans to a maximum number of lines, marking where they were cut
+/// off with an ellipsis in the colour of the last line.
+fn limit_lines<'a>(
+	spans: Vec<TextSpan<'a>>,
+	maximum_lines: usize,
+	ellipsis: &'a str,
+) -> Vec<TextSpan<'a>> {
+	let mut limited_spans = Vec::with_capacity(spans.len());
+	let mut remaining_newlines = maximum_lines.saturating_sub(1);
+
+	for span in spans {
+		let Some((newline_index, _)) = span.text.match_indices('\n').nth(remaining_newlines) else {
+			remaining_newlines -= span.text.matches('\n').count();
+			limited_spans.push(span);
+			continue;
+		};
+
+		if newline_index > 0 {
+			limited_spans.push(TextSpan {
+				text: &span.text[..newline_index],
+				..span
+			});
+		}
+		limited_spans.push(TextSpan {
+			text:   ellipsis,
+			colour: limited_spans
+				.last()
+				.map_or(span.colour, |last_span| last_span.colour),
+		});
+		break;
+	}
+
+	limited_spans
+}
+
+/// Splits text spans at the end of the last revealed line, so that the lines
+/// after it can be hidden while still taking up space.
+///
+/// Returns each span along with its relative scale and whether it's revealed.
+fn split_revealed_spans<'a, I>(spans: I, revealed_lines: usize) -> Vec<(TextSpan<'a>, f32, bool)>
+where
+	I: IntoIterator<Item = (TextSpan<'a>, f32)>,
+{
+	let mut revealed_spans = Vec::new();
+	let mut remaining_lines = revealed_lines;
+
+	for (span, relative_scale) in spans {
+		if remaining_lines == 0 {
+			revealed_spans.push((span, relative_scale, false));
+			continue;
+		}
+
+		// The newline stays with the revealed line, like with title slides
+		let mut split_index = None;
+		for (newline_index, _) in span.text.match_indices('\n') {
+			remaining_lines -= 1;
+			if remaining_lines == 0 {
+				split_index = Some(newline_index + 1);
+				break;
+			}
+		}
+		let Some(split_index) = split_index else {
+			revealed_spans.push((span, relative_scale, true));
+			continue;
+		};
+		let (revealed_text, hidden_text) = span.text.split_at(split_index);
+		revealed_spans.push((
+			TextSpan {
+				text:   revealed_text,
+				colour: span.colour,
+			},
+			relative_scale,
+			true,
+		));
+		if !hidden_text.is_empty() {
+			revealed_spans.push((
+				TextSpan {
+					text:   hidden_text,
+					colour: span.colour,
+				},
+				relative_scale,
+				false,
+			));
+		}
+	}
+
+	revealed_spans
+}
+
+/// Linearly interpolates between two colours.
+fn lerp_colour(from: LinearRgbaColour, to: LinearRgbaColour, amount: f32) -> LinearRgbaColour {
+	let mut colour = from;
+	for (channel, to_channel) in colour.iter_mut().zip(to) {
+		*channel += (to_channel - *channel) * amount;
+	}
+
+	colour
+}
+
+/// Whether a window of a size can be drawn to.
+fn is_drawable_size(size: PhysicalSize<u32>) -> bool {
+	size.width > 0 && size.height > 0
+}
+
+/// Multiplies the alpha of a colour by an opacity.
+fn with_opacity(colour: LinearRgbaColour, opacity: f32) -> LinearRgbaColour {
+	let [red, green, blue, alpha] = colour;
+
+	[red, green, blue, alpha * opacity]
+}
+
+/// Rounds a screen position to the nearest whole pixel.
+fn round_to_pixel((x, y): (f32, f32)) -> (f32, f32) {
+	(x.round(), y.round())
 }
 
 /// The need for this function is stupid.
@@ -472,3 +2376,378 @@ where
 
 	(result, remainder)
 }
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use gfx_glyph::HorizontalAlign;
+	use winit::dpi::PhysicalSize;
+
+	use super::{
+		align_horizontally,
+		anchor_in_area,
+		calculate_grid_cells,
+		calculate_scaling_factor,
+		calculate_text_layout,
+		is_drawable_size,
+		limit_lines,
+		screen_rect_to_vertices,
+		split_caption_area,
+		split_revealed_spans,
+		split_title_spans,
+		TextLayout,
+	};
+	use crate::presentation::{
+		split_colour_spans,
+		Anchor,
+		FitMode,
+		FitOverflow,
+		HorizontalAlignment,
+		TextSpan,
+		VerticalAlignment,
+	};
+
+	// Constants
+	const SCREEN_DIMENSIONS: (f32, f32) = (1920.0, 1080.0);
+	const USABLE_DIMENSIONS: (f32, f32) = (1440.0, 810.0);
+	/// The tolerance for floating-point error in the calculations.
+	const EPSILON: f32 = 0.01;
+
+	/// Scales and positions a section with the provided unscaled dimensions,
+	/// returning the layout along with the scaled dimensions.
+	fn layout_section(
+		unscaled_dimensions: (f32, f32),
+		fit_mode: FitMode,
+		fit_overflow: FitOverflow,
+		vertical_alignment: VerticalAlignment,
+	) -> (TextLayout, (f32, f32)) {
+		let scaling_factor = calculate_scaling_factor(
+			fit_mode,
+			USABLE_DIMENSIONS.0,
+			USABLE_DIMENSIONS.1,
+			unscaled_dimensions.0,
+			unscaled_dimensions.1,
+		);
+		let scaled_dimensions = (
+			unscaled_dimensions.0 * scaling_factor,
+			unscaled_dimensions.1 * scaling_factor,
+		);
+		let text_layout = calculate_text_layout(
+			SCREEN_DIMENSIONS,
+			USABLE_DIMENSIONS,
+			scaled_dimensions,
+			fit_overflow,
+			vertical_alignment,
+		);
+
+		(text_layout, scaled_dimensions)
+	}
+
+	/// Checks whether a section lies entirely within the usable space.
+	fn is_within_usable_space((text_layout, scaled_dimensions): (TextLayout, (f32, f32))) -> bool {
+		let usable_left = (SCREEN_DIMENSIONS.0 - USABLE_DIMENSIONS.0) / 2.0;
+		let usable_top = (SCREEN_DIMENSIONS.1 - USABLE_DIMENSIONS.1) / 2.0;
+		let (x, y) = text_layout.position;
+
+		x >= usable_left - EPSILON
+			&& y >= usable_top - EPSILON
+			&& x + scaled_dimensions.0 <= usable_left + USABLE_DIMENSIONS.0 + EPSILON
+			&& y + scaled_dimensions.1 <= usable_top + USABLE_DIMENSIONS.1 + EPSILON
+	}
+
+	#[test]
+	fn anchor_placement() {
+		// The corners of a rect in normalised coordinates, where the top of the screen
+		// is at `1.0`
+		let anchored_corners = |anchor| {
+			let (x, y) = anchor_in_area((50.0, 25.0), (25.0, 12.5, 150.0, 75.0), anchor);
+			let vertices = screen_rect_to_vertices(200.0, 100.0, x, y, 50.0, 25.0);
+
+			(vertices[1].pos, vertices[3].pos)
+		};
+
+		let actual_result = [
+			anchored_corners(Anchor::TopLeft),
+			anchored_corners(Anchor::Center),
+			anchored_corners(Anchor::BottomRight),
+		];
+
+		let expected_result = [
+			([-0.75, 0.75], [-0.25, 0.25]),
+			([-0.25, 0.25], [0.25, -0.25]),
+			([0.25, -0.25], [0.75, -0.75]),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn text_fits_usable_space() {
+		// Wide, tall, single-word, and multi-line sections
+		let unscaled_dimensions = [(40.0, 1.2), (3.0, 12.0), (4.5, 1.2), (20.0, 6.0)];
+		let vertical_alignments = [
+			VerticalAlignment::Top,
+			VerticalAlignment::Center,
+			VerticalAlignment::Bottom,
+		];
+
+		let actual_result = unscaled_dimensions
+			.iter()
+			.flat_map(|&dimensions| {
+				vertical_alignments.iter().map(move |&vertical_alignment| {
+					is_within_usable_space(layout_section(
+						dimensions,
+						FitMode::Both,
+						FitOverflow::Clip,
+						vertical_alignment,
+					))
+				})
+			})
+			.collect::<Vec<_>>();
+
+		let expected_result = vec![true; unscaled_dimensions.len() * vertical_alignments.len()];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn text_fills_usable_width() {
+		let (text_layout, scaled_dimensions) = layout_section(
+			(40.0, 1.2),
+			FitMode::Both,
+			FitOverflow::Clip,
+			VerticalAlignment::Center,
+		);
+		let usable_left = (SCREEN_DIMENSIONS.0 - USABLE_DIMENSIONS.0) / 2.0;
+
+		let actual_result = (
+			(text_layout.position.0 - usable_left).abs() < EPSILON,
+			(scaled_dimensions.0 - USABLE_DIMENSIONS.0).abs() < EPSILON,
+		);
+
+		let expected_result = (true, true);
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn overflow_aligned_to_start() {
+		let (text_layout, _) = layout_section(
+			(3.0, 12.0),
+			FitMode::Width,
+			FitOverflow::Start,
+			VerticalAlignment::Center,
+		);
+		let usable_left = (SCREEN_DIMENSIONS.0 - USABLE_DIMENSIONS.0) / 2.0;
+		let usable_top = (SCREEN_DIMENSIONS.1 - USABLE_DIMENSIONS.1) / 2.0;
+
+		let actual_result = (
+			(text_layout.position.0 - usable_left).abs() < EPSILON,
+			(text_layout.position.1 - usable_top).abs() < EPSILON,
+		);
+
+		let expected_result = (true, true);
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn horizontal_alignment() {
+		let (text_layout, scaled_dimensions) = layout_section(
+			(4.5, 1.2),
+			FitMode::Both,
+			FitOverflow::Clip,
+			VerticalAlignment::Center,
+		);
+		let usable_left = (SCREEN_DIMENSIONS.0 - USABLE_DIMENSIONS.0) / 2.0;
+
+		let actual_result = [
+			(HorizontalAlignment::Left, usable_left),
+			(HorizontalAlignment::Center, SCREEN_DIMENSIONS.0 / 2.0),
+			(
+				HorizontalAlignment::Right,
+				usable_left + USABLE_DIMENSIONS.0,
+			),
+		]
+		.map(|(horizontal_alignment, expected_x)| {
+			let (h_align, x) = align_horizontally(
+				horizontal_alignment,
+				SCREEN_DIMENSIONS.0,
+				USABLE_DIMENSIONS.0,
+				text_layout.position.0,
+				scaled_dimensions.0,
+			);
+
+			(h_align, (x - expected_x).abs() < EPSILON)
+		});
+
+		let expected_result = [
+			(HorizontalAlign::Left, true),
+			(HorizontalAlign::Center, true),
+			(HorizontalAlign::Right, true),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn grid_cells() {
+		let actual_result = [
+			calculate_grid_cells(2, (0.0, 0.0, 210.0, 100.0), 10.0),
+			calculate_grid_cells(3, (10.0, 20.0, 210.0, 210.0), 10.0),
+			calculate_grid_cells(0, (0.0, 0.0, 100.0, 100.0), 10.0),
+		];
+
+		let expected_result = [
+			vec![(0.0, 0.0, 100.0, 100.0), (110.0, 0.0, 100.0, 100.0)],
+			vec![
+				(10.0, 20.0, 100.0, 100.0),
+				(120.0, 20.0, 100.0, 100.0),
+				(65.0, 130.0, 100.0, 100.0),
+			],
+			vec![],
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn caption_areas() {
+		let actual_result = [
+			split_caption_area((10.0, 20.0, 200.0, 100.0), 5.0),
+			split_caption_area((0.0, 0.0, 100.0, 10.0), 5.0),
+		];
+
+		let expected_result = [
+			((10.0, 20.0, 200.0, 80.0), (10.0, 105.0, 200.0, 15.0)),
+			((0.0, 0.0, 100.0, 8.0), (0.0, 13.0, 100.0, 0.0)),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn title_spans() {
+		let actual_result = split_title_spans(
+			split_colour_spans("A [color=#ff0000]Title\nA[/color] subtitle\nMore"),
+			0.5,
+		);
+
+		let red = split_colour_spans("[color=#ff0000]red[/color]")[0].colour;
+		let expected_result = vec![
+			(
+				TextSpan {
+					text:   "A ",
+					colour: None,
+				},
+				1.0,
+			),
+			(
+				TextSpan {
+					text:   "Title\n",
+					colour: red,
+				},
+				1.0,
+			),
+			(
+				TextSpan {
+					text:   "A",
+					colour: red,
+				},
+				0.5,
+			),
+			(
+				TextSpan {
+					text:   " subtitle\nMore",
+					colour: None,
+				},
+				0.5,
+			),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn minimised_window_sizes() {
+		let actual_result = [
+			PhysicalSize::new(1920, 1080),
+			PhysicalSize::new(0, 0),
+			PhysicalSize::new(1920, 0),
+			PhysicalSize::new(0, 1080),
+		]
+		.map(is_drawable_size);
+
+		let expected_result = [true, false, false, false];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn revealed_spans() {
+		let spans = split_colour_spans("Agenda\n- [color=#ff0000]First\n- Second[/color]\n- Third")
+			.into_iter()
+			.map(|span| (span, 1.0));
+
+		let actual_result = split_revealed_spans(spans, 2)
+			.into_iter()
+			.map(|(span, _, is_revealed)| (span.text, is_revealed))
+			.collect::<Vec<_>>();
+
+		let expected_result = vec![
+			("Agenda\n- ", true),
+			("First\n", true),
+			("- Second", false),
+			("\n- Third", false),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn limited_lines() {
+		let red = split_colour_spans("[color=#ff0000]red[/color]")[0].colour;
+		let actual_result = [
+			limit_lines(split_colour_spans("First\nSecond\nThird"), 2, "\u{2026}"),
+			limit_lines(split_colour_spans("First\nSecond"), 2, "\u{2026}"),
+			limit_lines(
+				split_colour_spans("First\n[color=#ff0000]Second\nThird[/color]"),
+				2,
+				"\u{2026}",
+			),
+		];
+
+		let expected_result = [
+			vec![
+				TextSpan {
+					text:   "First\nSecond",
+					colour: None,
+				},
+				TextSpan {
+					text:   "\u{2026}",
+					colour: None,
+				},
+			],
+			vec![TextSpan {
+				text:   "First\nSecond",
+				colour: None,
+			}],
+			vec![
+				TextSpan {
+					text:   "First\n",
+					colour: None,
+				},
+				TextSpan {
+					text:   "Second",
+					colour: red,
+				},
+				TextSpan {
+					text:   "\u{2026}",
+					colour: red,
+				},
+			],
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
@@ -0,0 +1,17 @@
+#version 150 core
+
+uniform vec4 u_Colour;
+uniform vec2 u_ScreenSize;
+uniform vec2 u_Centre;
+uniform float u_Radius;
+uniform float u_EdgeWidth;
+
+in vec2 v_Uv;
+
+out vec4 Target0;
+
+void main() {
+    float cursorDistance = length(v_Uv * u_ScreenSize - u_Centre);
+    float coverage = smoothstep(u_Radius - u_EdgeWidth, u_Radius, cursorDistance);
+    Target0 = vec4(u_Colour.rgb, u_Colour.a * coverage);
+}
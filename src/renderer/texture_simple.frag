@@ -1,11 +1,12 @@
 #version 150 core
 
 uniform sampler2D t_Current;
+uniform vec4 u_Tint;
 
 in vec2 v_Uv;
 
 out vec4 Target0;
 
 void main() {
-    Target0 = texture(t_Current, v_Uv);
+    Target0 = texture(t_Current, v_Uv) * u_Tint;
 }
@@ -0,0 +1,70 @@
+//! Saving screenshots of the current slide.
+
+// Uses
+use std::{
+	path::PathBuf,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use breeze::date::{civil_date, SECONDS_PER_DAY};
+use image::RgbaImage;
+
+// Constants
+const SCREENSHOT_FILE_NAME_PREFIX: &str = "breeze-";
+const SCREENSHOT_FILE_EXTENSION: &str = "png";
+
+/// Saves a screenshot to the working directory, in a file named after the
+/// current time, and returns its path.
+pub fn save_screenshot(image: &RgbaImage) -> Result<PathBuf, String> {
+	let seconds_since_epoch = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |duration| duration.as_secs());
+	let path = PathBuf::from(screenshot_file_name(seconds_since_epoch));
+
+	image.save(&path).map_err(|_| {
+		format!(
+			"unable to save the screenshot to\n\"{}\"!",
+			path.to_string_lossy()
+		)
+	})?;
+
+	Ok(path)
+}
+
+/// Names a screenshot after the time it was taken, in UTC, so that the names
+/// sort chronologically.
+fn screenshot_file_name(seconds_since_epoch: u64) -> String {
+	let (year, month, day) = civil_date(seconds_since_epoch / SECONDS_PER_DAY);
+	let seconds_of_day = seconds_since_epoch % SECONDS_PER_DAY;
+
+	format!(
+		"{SCREENSHOT_FILE_NAME_PREFIX}{year:04}-{month:02}-{day:02}-{:02}{:02}{:02}.\
+		 {SCREENSHOT_FILE_EXTENSION}",
+		seconds_of_day / 3600,
+		seconds_of_day / 60 % 60,
+		seconds_of_day % 60,
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::screenshot_file_name;
+
+	#[test]
+	fn file_names() {
+		let actual_result = [
+			screenshot_file_name(0),
+			screenshot_file_name(951_782_400),
+			screenshot_file_name(1_792_089_045),
+		];
+
+		let expected_result = [
+			"breeze-1970-01-01-000000.png".to_owned(),
+			"breeze-2000-02-29-000000.png".to_owned(),
+			"breeze-2026-10-15-183045.png".to_owned(),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
@@ -0,0 +1,74 @@
+//! Shuffling the order of the slides, with a seed so that the order can be
+//! reproduced.
+
+// Uses
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A seed that differs between runs, for when one isn't provided.
+pub fn random_seed() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |duration| duration.as_nanos() as u64)
+}
+
+/// Shuffles items into an order determined entirely by the seed, using the
+/// Fisher-Yates shuffle.
+pub fn shuffle<T>(items: &mut [T], seed: u64) {
+	let mut generator = SplitMix64 { state: seed };
+	for index in (1..items.len()).rev() {
+		// The modulo bias is negligible for any realistic number of slides
+		let other_index = (generator.next() % (index as u64 + 1)) as usize;
+		items.swap(index, other_index);
+	}
+}
+
+/// A small, fast pseudo-random number generator, which is more than enough
+/// for shuffling slides.
+///
+/// See: https://prng.di.unimi.it/splitmix64.c
+struct SplitMix64 {
+	state: u64,
+}
+
+impl SplitMix64 {
+	fn next(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+		let mut value = self.state;
+		value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+		value ^ (value >> 31)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use super::shuffle;
+
+	fn shuffled(seed: u64) -> Vec<usize> {
+		let mut items = (0..10).collect::<Vec<_>>();
+		shuffle(&mut items, seed);
+
+		items
+	}
+
+	#[test]
+	fn reproducible() {
+		let actual_result = [shuffled(42) == shuffled(42), shuffled(42) == shuffled(43)];
+
+		let expected_result = [true, false];
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn permutation() {
+		let mut actual_result = shuffled(7);
+		actual_result.sort_unstable();
+
+		let expected_result = (0..10).collect::<Vec<_>>();
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
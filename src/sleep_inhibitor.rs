@@ -0,0 +1,141 @@
+//! Keeping the system from sleeping or starting the screensaver while a
+//! presentation is running.
+//!
+//! On Windows, this is done by setting the thread's execution state. Elsewhere,
+//! it's done by running the platform's inhibitor tool until the inhibition is
+//! released.
+
+// Uses
+#[cfg(not(target_os = "windows"))]
+use std::process::{id as process_id, Child, Command, Stdio};
+
+#[cfg(target_os = "windows")]
+use windows_sys::Win32::System::Power::{
+	SetThreadExecutionState,
+	ES_CONTINUOUS,
+	ES_DISPLAY_REQUIRED,
+	ES_SYSTEM_REQUIRED,
+};
+
+// Constants
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const INHIBITION_REASON: &str = "Running a presentation";
+
+/// Inhibits sleep while it's wanted, releasing the inhibition when it isn't
+/// or when dropped.
+pub struct SleepInhibitor {
+	/// Whether sleep may be inhibited at all.
+	enabled:    bool,
+	inhibition: Option<Inhibition>,
+}
+
+impl SleepInhibitor {
+	pub fn new(enabled: bool) -> Self {
+		Self {
+			enabled,
+			inhibition: None,
+		}
+	}
+
+	/// Inhibits sleep, or releases the inhibition so that the system can sleep
+	/// again.
+	///
+	/// Returns `false` if sleep couldn't be inhibited, such as if the system
+	/// doesn't support it.
+	pub fn set_inhibited(&mut self, inhibited: bool) -> bool {
+		if !(self.enabled && inhibited) {
+			self.inhibition = None;
+			return true;
+		}
+		if self.inhibition.is_none() {
+			self.inhibition = Inhibition::start();
+		}
+
+		self.inhibition.is_some()
+	}
+}
+
+/// An active inhibition, which is released when dropped.
+#[cfg(target_os = "windows")]
+struct Inhibition;
+
+#[cfg(target_os = "windows")]
+impl Inhibition {
+	fn start() -> Option<Self> {
+		// SAFETY: Setting the execution state has no preconditions, and the flags
+		// are valid
+		let previous_state = unsafe {
+			SetThreadExecutionState(ES_CONTINUOUS | ES_DISPLAY_REQUIRED | ES_SYSTEM_REQUIRED)
+		};
+
+		// The previous state is zero only if the call failed
+		(previous_state != 0).then_some(Self)
+	}
+}
+
+#[cfg(target_os = "windows")]
+impl Drop for Inhibition {
+	fn drop(&mut self) {
+		// SAFETY: Setting the execution state has no preconditions, and the flags
+		// are valid
+		unsafe {
+			SetThreadExecutionState(ES_CONTINUOUS);
+		}
+	}
+}
+
+/// An active inhibition, which is released when dropped.
+///
+/// The inhibitor tool is told to stop once this process exits, so that the
+/// inhibition is released even if this process doesn't get to drop it.
+#[cfg(not(target_os = "windows"))]
+struct Inhibition {
+	inhibitor_process: Child,
+}
+
+#[cfg(not(target_os = "windows"))]
+impl Inhibition {
+	fn start() -> Option<Self> {
+		#[cfg(target_os = "macos")]
+		let mut command = {
+			let mut command = Command::new("caffeinate");
+			command
+				.args(["-d", "-i", "-w"])
+				.arg(process_id().to_string());
+			command
+		};
+		#[cfg(not(target_os = "macos"))]
+		let mut command = {
+			let mut command = Command::new("systemd-inhibit");
+			command
+				.args([
+					"--what=idle:sleep",
+					"--who=breeze",
+					&format!("--why={INHIBITION_REASON}"),
+					"--mode=block",
+					"tail",
+				])
+				.arg(format!("--pid={}", process_id()))
+				.args(["-f", "/dev/null"]);
+			command
+		};
+
+		command
+			.stdin(Stdio::null())
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.spawn()
+			.ok()
+			.map(|inhibitor_process| Self { inhibitor_process })
+	}
+}
+
+#[cfg(not(target_os = "windows"))]
+impl Drop for Inhibition {
+	fn drop(&mut self) {
+		// The process may have already exited, in which case there's nothing to
+		// release
+		let _ = self.inhibitor_process.kill();
+		let _ = self.inhibitor_process.wait();
+	}
+}
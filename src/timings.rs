@@ -0,0 +1,99 @@
+//! Timings files, which list the times to advance the slides at, such as to
+//! keep them in sync with a recording.
+//!
+//! Each line is a cue in the format `mm:ss`, measured from the start of the
+//! presentation. The first cue advances to the second slide, the next to the
+//! third, and so on. Blank lines and lines starting with `#` are ignored.
+
+// Uses
+use std::{fs::read_to_string, path::Path, time::Duration};
+
+use breeze::date::parse_minutes_and_seconds;
+
+// Constants
+const COMMENT_MARKER: char = '#';
+
+/// Loads the cues from a timings file, in order.
+pub fn load_timings(path: &Path) -> Result<Vec<Duration>, String> {
+	let contents = read_to_string(path).map_err(|_| {
+		format!(
+			"unable to read the timings file\n\"{}\"!",
+			path.to_string_lossy()
+		)
+	})?;
+
+	parse_timings(contents.as_str()).map_err(|error| {
+		format!(
+			"{error}\n(in the timings file\n\"{}\")",
+			path.to_string_lossy()
+		)
+	})
+}
+
+/// Parses the cues of a timings file, sorting them so that they're in order
+/// even if the file isn't.
+fn parse_timings(contents: &str) -> Result<Vec<Duration>, String> {
+	let mut cues = Vec::new();
+	for (line_index, line) in contents.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() || line.starts_with(COMMENT_MARKER) {
+			continue;
+		}
+
+		cues.push(
+			parse_minutes_and_seconds(line)
+				.ok_or_else(|| format!("invalid cue \"{line}\" on line {}!", line_index + 1))?,
+		);
+	}
+	cues.sort_unstable();
+
+	Ok(cues)
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::time::Duration;
+
+	use super::parse_timings;
+
+	#[test]
+	fn cues() {
+		let actual_result = parse_timings(
+			r"
+# Introduction
+00:05
+1:30
+
+0:45
+",
+		);
+
+		let expected_result = Ok(vec![
+			Duration::from_secs(5),
+			Duration::from_secs(45),
+			Duration::from_secs(90),
+		]);
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn invalid_cues() {
+		let actual_result = [
+			parse_timings("0:05\n5"),
+			parse_timings("0:60"),
+			parse_timings("a:05"),
+			parse_timings("307445734561825861:00"),
+		];
+
+		let expected_result = [
+			Err("invalid cue \"5\" on line 2!".to_owned()),
+			Err("invalid cue \"0:60\" on line 1!".to_owned()),
+			Err("invalid cue \"a:05\" on line 1!".to_owned()),
+			Err("invalid cue \"307445734561825861:00\" on line 1!".to_owned()),
+		];
+
+		assert_eq!(expected_result, actual_result);
+	}
+}
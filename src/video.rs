@@ -0,0 +1,236 @@
+//! Video decoding for video slides.
+//!
+//! Decoding is delegated to [`ffmpeg`], which is expected to be available on
+//! the `PATH`. It's run as a separate process that outputs raw RGBA frames,
+//! which are read on a background thread so that the event loop is never
+//! blocked.
+//!
+//! [`ffmpeg`]: https://ffmpeg.org/
+
+// Uses
+use std::{
+	io::Read,
+	path::Path,
+	process::{Child, ChildStdout, Command, Stdio},
+	sync::mpsc::{sync_channel, Receiver, TryRecvError},
+	thread,
+	time::{Duration, Instant},
+};
+
+// Constants
+const FFMPEG_COMMAND: &str = "ffmpeg";
+const FFPROBE_COMMAND: &str = "ffprobe";
+/// The frame rate to assume if the video doesn't report a usable one.
+const FALLBACK_FRAME_RATE: f64 = 30.0;
+/// The number of decoded frames to buffer ahead of playback.
+const FRAME_BUFFER_COUNT: usize = 4;
+const BYTES_PER_PIXEL: usize = 4;
+
+/// The properties of a video that are required for playback.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VideoInfo {
+	pub dimensions:     (u32, u32),
+	pub frame_duration: Duration,
+}
+
+/// A running video decode, yielding frames at the rate they should be shown.
+pub struct VideoStream {
+	decoder:        Child,
+	frames:         Receiver<Vec<u8>>,
+	frame_duration: Duration,
+	start_time:     Instant,
+	frames_taken:   u32,
+	finished:       bool,
+}
+
+impl VideoStream {
+	/// Starts decoding the video at the provided path.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the decoder can't be started.
+	///
+	/// # Panics
+	///
+	/// Panics if the output of the decoder isn't available, which should never
+	/// happen.
+	pub fn start(path: &Path, info: VideoInfo) -> Result<Self, String> {
+		let mut decoder = Command::new(FFMPEG_COMMAND)
+			.args(["-v", "error", "-i"])
+			.arg(path)
+			.args(["-f", "rawvideo", "-pix_fmt", "rgba", "-"])
+			.stdin(Stdio::null())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::null())
+			.spawn()
+			.map_err(|_| format!("unable to start `{FFMPEG_COMMAND}`"))?;
+
+		let decoder_output = decoder
+			.stdout
+			.take()
+			.expect("the output of the decoder is piped");
+		let frame_size = info.dimensions.0 as usize * info.dimensions.1 as usize * BYTES_PER_PIXEL;
+		let (frame_sender, frames) = sync_channel(FRAME_BUFFER_COUNT);
+		thread::spawn(move || {
+			read_frames(decoder_output, frame_size, |frame| {
+				frame_sender.send(frame).is_ok()
+			});
+		});
+
+		Ok(Self {
+			decoder,
+			frames,
+			frame_duration: info.frame_duration,
+			start_time: Instant::now(),
+			frames_taken: 0,
+			finished: false,
+		})
+	}
+
+	/// The time at which the next frame should be shown, or `None` if the video
+	/// has finished.
+	#[must_use]
+	pub fn next_frame_time(&self) -> Option<Instant> {
+		(!self.finished).then(|| self.start_time + self.frame_duration * self.frames_taken)
+	}
+
+	/// Takes the most recent frame that is due to be shown, if any.
+	///
+	/// Frames that are already overdue are skipped, so that playback keeps
+	/// pace with the wall clock if rendering falls behind.
+	pub fn take_due_frame(&mut self) -> Option<Vec<u8>> {
+		let now = Instant::now();
+		let mut due_frame = None;
+
+		while let Some(next_frame_time) = self.next_frame_time() {
+			if next_frame_time > now {
+				break;
+			}
+
+			match self.frames.try_recv() {
+				Ok(frame) => {
+					due_frame = Some(frame);
+					self.frames_taken += 1;
+				}
+				Err(TryRecvError::Empty) => break,
+				Err(TryRecvError::Disconnected) => self.finished = true,
+			}
+		}
+
+		due_frame
+	}
+}
+
+impl Drop for VideoStream {
+	fn drop(&mut self) {
+		// The decoder may have already exited, in which case there's nothing to do
+		let _ = self.decoder.kill();
+		let _ = self.decoder.wait();
+	}
+}
+
+/// Reads the dimensions and frame rate of a video.
+///
+/// # Errors
+///
+/// Returns an error if `ffprobe` can't be run, or if it can't read the video.
+pub fn probe_video(path: &Path) -> Result<VideoInfo, String> {
+	let output = Command::new(FFPROBE_COMMAND)
+		.args([
+			"-v",
+			"error",
+			"-select_streams",
+			"v:0",
+			"-show_entries",
+			"stream=width,height,avg_frame_rate",
+			"-of",
+			"csv=p=0",
+		])
+		.arg(path)
+		.stdin(Stdio::null())
+		.stderr(Stdio::null())
+		.output()
+		.map_err(|_| format!("unable to start `{FFPROBE_COMMAND}`"))?;
+	if !output.status.success() {
+		return Err("the video could not be read".to_owned());
+	}
+
+	parse_probe_output(String::from_utf8_lossy(&output.stdout).as_ref())
+		.ok_or_else(|| "the video has no usable video stream".to_owned())
+}
+
+/// Parses the output of `ffprobe`, which is in the format
+/// `width,height,frame_rate`.
+fn parse_probe_output(output: &str) -> Option<VideoInfo> {
+	let mut fields = output.lines().next()?.trim().split(',');
+	let width = fields.next()?.parse::<u32>().ok()?;
+	let height = fields.next()?.parse::<u32>().ok()?;
+	let frame_rate = fields
+		.next()
+		.and_then(parse_frame_rate)
+		.unwrap_or(FALLBACK_FRAME_RATE);
+
+	if width == 0 || height == 0 {
+		return None;
+	}
+
+	Some(VideoInfo {
+		dimensions:     (width, height),
+		frame_duration: Duration::from_secs_f64(1.0 / frame_rate),
+	})
+}
+
+/// Parses a frame rate in the fractional format `ffprobe` uses (eg.
+/// `30000/1001`).
+fn parse_frame_rate(value: &str) -> Option<f64> {
+	let (numerator, denominator) = value.split_once('/')?;
+	let frame_rate = numerator.parse::<f64>().ok()? / denominator.parse::<f64>().ok()?;
+
+	(frame_rate.is_finite() && frame_rate > 0.0).then_some(frame_rate)
+}
+
+/// Reads whole frames from the decoder output until it ends or `on_frame`
+/// returns `false`.
+fn read_frames<F>(mut decoder_output: ChildStdout, frame_size: usize, mut on_frame: F)
+where
+	F: FnMut(Vec<u8>) -> bool,
+{
+	loop {
+		let mut frame = vec![0; frame_size];
+		if decoder_output.read_exact(&mut frame).is_err() || !on_frame(frame) {
+			return;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	// Uses
+	use std::time::Duration;
+
+	use super::{parse_probe_output, VideoInfo};
+
+	#[test]
+	fn probe_output() {
+		let actual_result = parse_probe_output("1920,1080,25/1\n");
+
+		let expected_result = Some(VideoInfo {
+			dimensions:     (1920, 1080),
+			frame_duration: Duration::from_millis(40),
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+
+	#[test]
+	fn probe_output_unknown_frame_rate() {
+		let actual_result = parse_probe_output("640,480,0/0");
+
+		let expected_result = Some(VideoInfo {
+			dimensions:     (640, 480),
+			frame_duration: Duration::from_secs_f64(1.0 / 30.0),
+		});
+
+		assert_eq!(expected_result, actual_result);
+	}
+}